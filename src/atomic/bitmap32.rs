@@ -0,0 +1,125 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::{Bitmap32, BitmapError, BitmapSize};
+
+/// A bitmap of length 32 that can be shared between threads, wrapping an `AtomicU32`.
+///
+/// Every method works through a shared reference and takes the memory `Ordering` to use, with
+/// each single-bit operation being one atomic instruction. This makes it suitable for lock-free
+/// structures such as allocation bitmaps.
+///
+/// # Examples
+/// ```rust
+/// use fixed_bitmaps::AtomicBitmap32;
+/// use std::sync::atomic::Ordering;
+///
+/// let bitmap = AtomicBitmap32::default();
+///
+/// bitmap.set(3, true, Ordering::SeqCst).unwrap();
+/// assert!(bitmap.get(3, Ordering::SeqCst).unwrap());
+///
+/// bitmap.atomic_toggle(3, Ordering::SeqCst).unwrap();
+/// assert!(!bitmap.get(3, Ordering::SeqCst).unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicBitmap32(AtomicU32);
+
+impl AtomicBitmap32 {
+    pub fn capacity() -> usize {
+        Bitmap32::MAP_LENGTH
+    }
+
+    /// Creates a new bitmap with every bit set to the value provided.
+    pub fn new(value: bool) -> AtomicBitmap32 {
+        AtomicBitmap32::from(Bitmap32::new(value))
+    }
+
+    /// Loads a snapshot of the whole bitmap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ordering` is `Release` or `AcqRel`, as for `AtomicU32::load()`.
+    pub fn load(&self, ordering: Ordering) -> Bitmap32 {
+        Bitmap32::from(self.0.load(ordering))
+    }
+
+    /// Overwrites the whole bitmap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ordering` is `Acquire` or `AcqRel`, as for `AtomicU32::store()`.
+    pub fn store(&self, bitmap: Bitmap32, ordering: Ordering) {
+        self.0.store(bitmap.into_inner(), ordering);
+    }
+
+    /// Consumes the atomic bitmap, returning the plain bitmap it holds.
+    pub fn into_inner(self) -> Bitmap32 {
+        Bitmap32::from(self.0.into_inner())
+    }
+
+    fn mask(index: usize) -> Result<u32, BitmapError> {
+        if index >= Bitmap32::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap32::MAP_LENGTH,
+            });
+        }
+
+        Ok(1 << index)
+    }
+
+    /// Gets the bit at the given index, returning an `Err<BitmapError>` if it's out of bounds.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ordering` is `Release` or `AcqRel`, as for `AtomicU32::load()`.
+    pub fn get(&self, index: usize, ordering: Ordering) -> Result<bool, BitmapError> {
+        let mask = AtomicBitmap32::mask(index)?;
+        Ok(self.0.load(ordering) & mask > 0)
+    }
+
+    /// Atomically sets the bit at the given index to the value provided, using `fetch_or` to
+    /// set it or `fetch_and` to clear it. Returns an `Err<BitmapError>` if the index is out of
+    /// bounds, in which case the bitmap's state remains unchanged.
+    pub fn set(&self, index: usize, value: bool, ordering: Ordering) -> Result<(), BitmapError> {
+        let mask = AtomicBitmap32::mask(index)?;
+        if value {
+            self.0.fetch_or(mask, ordering);
+        } else {
+            self.0.fetch_and(!mask, ordering);
+        }
+
+        Ok(())
+    }
+
+    /// Atomically flips the bit at the given index using `fetch_xor`. Returns an
+    /// `Err<BitmapError>` if the index is out of bounds, in which case the bitmap's state
+    /// remains unchanged.
+    pub fn atomic_toggle(&self, index: usize, ordering: Ordering) -> Result<(), BitmapError> {
+        let mask = AtomicBitmap32::mask(index)?;
+        self.0.fetch_xor(mask, ordering);
+        Ok(())
+    }
+
+    /// Atomically sets the bit at the given index, returning its previous value. Returns an
+    /// `Err<BitmapError>` if the index is out of bounds, in which case the bitmap's state
+    /// remains unchanged.
+    pub fn fetch_set(&self, index: usize, ordering: Ordering) -> Result<bool, BitmapError> {
+        let mask = AtomicBitmap32::mask(index)?;
+        Ok(self.0.fetch_or(mask, ordering) & mask > 0)
+    }
+
+    /// Atomically clears the bit at the given index, returning its previous value. Returns an
+    /// `Err<BitmapError>` if the index is out of bounds, in which case the bitmap's state
+    /// remains unchanged.
+    pub fn fetch_clear(&self, index: usize, ordering: Ordering) -> Result<bool, BitmapError> {
+        let mask = AtomicBitmap32::mask(index)?;
+        Ok(self.0.fetch_and(!mask, ordering) & mask > 0)
+    }
+}
+
+impl From<Bitmap32> for AtomicBitmap32 {
+    fn from(bitmap: Bitmap32) -> Self {
+        AtomicBitmap32(AtomicU32::new(bitmap.into_inner()))
+    }
+}
@@ -0,0 +1,125 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{Bitmap8, BitmapError, BitmapSize};
+
+/// A bitmap of length 8 that can be shared between threads, wrapping an `AtomicU8`.
+///
+/// Every method works through a shared reference and takes the memory `Ordering` to use, with
+/// each single-bit operation being one atomic instruction. This makes it suitable for lock-free
+/// structures such as allocation bitmaps.
+///
+/// # Examples
+/// ```rust
+/// use fixed_bitmaps::AtomicBitmap8;
+/// use std::sync::atomic::Ordering;
+///
+/// let bitmap = AtomicBitmap8::default();
+///
+/// bitmap.set(3, true, Ordering::SeqCst).unwrap();
+/// assert!(bitmap.get(3, Ordering::SeqCst).unwrap());
+///
+/// bitmap.atomic_toggle(3, Ordering::SeqCst).unwrap();
+/// assert!(!bitmap.get(3, Ordering::SeqCst).unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicBitmap8(AtomicU8);
+
+impl AtomicBitmap8 {
+    pub fn capacity() -> usize {
+        Bitmap8::MAP_LENGTH
+    }
+
+    /// Creates a new bitmap with every bit set to the value provided.
+    pub fn new(value: bool) -> AtomicBitmap8 {
+        AtomicBitmap8::from(Bitmap8::new(value))
+    }
+
+    /// Loads a snapshot of the whole bitmap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ordering` is `Release` or `AcqRel`, as for `AtomicU8::load()`.
+    pub fn load(&self, ordering: Ordering) -> Bitmap8 {
+        Bitmap8::from(self.0.load(ordering))
+    }
+
+    /// Overwrites the whole bitmap.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ordering` is `Acquire` or `AcqRel`, as for `AtomicU8::store()`.
+    pub fn store(&self, bitmap: Bitmap8, ordering: Ordering) {
+        self.0.store(bitmap.into_inner(), ordering);
+    }
+
+    /// Consumes the atomic bitmap, returning the plain bitmap it holds.
+    pub fn into_inner(self) -> Bitmap8 {
+        Bitmap8::from(self.0.into_inner())
+    }
+
+    fn mask(index: usize) -> Result<u8, BitmapError> {
+        if index >= Bitmap8::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap8::MAP_LENGTH,
+            });
+        }
+
+        Ok(1 << index)
+    }
+
+    /// Gets the bit at the given index, returning an `Err<BitmapError>` if it's out of bounds.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `ordering` is `Release` or `AcqRel`, as for `AtomicU8::load()`.
+    pub fn get(&self, index: usize, ordering: Ordering) -> Result<bool, BitmapError> {
+        let mask = AtomicBitmap8::mask(index)?;
+        Ok(self.0.load(ordering) & mask > 0)
+    }
+
+    /// Atomically sets the bit at the given index to the value provided, using `fetch_or` to
+    /// set it or `fetch_and` to clear it. Returns an `Err<BitmapError>` if the index is out of
+    /// bounds, in which case the bitmap's state remains unchanged.
+    pub fn set(&self, index: usize, value: bool, ordering: Ordering) -> Result<(), BitmapError> {
+        let mask = AtomicBitmap8::mask(index)?;
+        if value {
+            self.0.fetch_or(mask, ordering);
+        } else {
+            self.0.fetch_and(!mask, ordering);
+        }
+
+        Ok(())
+    }
+
+    /// Atomically flips the bit at the given index using `fetch_xor`. Returns an
+    /// `Err<BitmapError>` if the index is out of bounds, in which case the bitmap's state
+    /// remains unchanged.
+    pub fn atomic_toggle(&self, index: usize, ordering: Ordering) -> Result<(), BitmapError> {
+        let mask = AtomicBitmap8::mask(index)?;
+        self.0.fetch_xor(mask, ordering);
+        Ok(())
+    }
+
+    /// Atomically sets the bit at the given index, returning its previous value. Returns an
+    /// `Err<BitmapError>` if the index is out of bounds, in which case the bitmap's state
+    /// remains unchanged.
+    pub fn fetch_set(&self, index: usize, ordering: Ordering) -> Result<bool, BitmapError> {
+        let mask = AtomicBitmap8::mask(index)?;
+        Ok(self.0.fetch_or(mask, ordering) & mask > 0)
+    }
+
+    /// Atomically clears the bit at the given index, returning its previous value. Returns an
+    /// `Err<BitmapError>` if the index is out of bounds, in which case the bitmap's state
+    /// remains unchanged.
+    pub fn fetch_clear(&self, index: usize, ordering: Ordering) -> Result<bool, BitmapError> {
+        let mask = AtomicBitmap8::mask(index)?;
+        Ok(self.0.fetch_and(!mask, ordering) & mask > 0)
+    }
+}
+
+impl From<Bitmap8> for AtomicBitmap8 {
+    fn from(bitmap: Bitmap8) -> Self {
+        AtomicBitmap8(AtomicU8::new(bitmap.into_inner()))
+    }
+}
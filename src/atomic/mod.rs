@@ -0,0 +1,21 @@
+//! Bitmaps wrapping the atomic integer types, which can be shared and modified between threads
+//! without a lock. They're all behind the `atomic` feature, and each width is only available on
+//! targets that support atomics of that width.
+
+#[cfg(all(feature = "atomic", target_has_atomic = "16"))]
+mod bitmap16;
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
+mod bitmap32;
+#[cfg(all(feature = "atomic", target_has_atomic = "64"))]
+mod bitmap64;
+#[cfg(all(feature = "atomic", target_has_atomic = "8"))]
+mod bitmap8;
+
+#[cfg(all(feature = "atomic", target_has_atomic = "16"))]
+pub use bitmap16::AtomicBitmap16;
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
+pub use bitmap32::AtomicBitmap32;
+#[cfg(all(feature = "atomic", target_has_atomic = "64"))]
+pub use bitmap64::AtomicBitmap64;
+#[cfg(all(feature = "atomic", target_has_atomic = "8"))]
+pub use bitmap8::AtomicBitmap8;
@@ -12,6 +12,7 @@
 //!   modules. This is the method I chose, hence this binary.
 //!
 //! This program currently focusses on two files: `fixed_bitmaps/src/primitives/bitmap128.rs` and `fixed_bitmaps/tests/primitives/bitmap128.rs`.
+//! The oversized bitmaps are generated in the same way from `bitmap_kb.rs`, and the atomic bitmaps from `atomic/bitmap64.rs`.
 //! When benchmarking becomes a thing I'll probably add in a file there too.
 //!
 //! All it does is when run, it takes the contents of the above two files, and overwrites or creates 5 more files each, one
@@ -42,6 +43,14 @@ const WITH_OVERSIZED: [(&str, &str, &str, &str); 5] = [
     ("4_096", "4096", "bitmap_4096", "bitmap_4096"),
 ];
 
+const REPLACE_ATOMIC: (&str, &str, &str, &str) = ("u64", "Bitmap64", "64", "bitmap64");
+
+const WITH_ATOMIC: [(&str, &str, &str, &str); 3] = [
+    ("u32", "32", "32", "bitmap32"),
+    ("u16", "16", "16", "bitmap16"),
+    ("u8", "8", "8", "bitmap8"),
+];
+
 fn create_or_replace(
     src_dir_path: String,
     replace: (&str, &str, &str, &str),
@@ -110,9 +119,27 @@ fn create_or_replace_oversized_tests() {
     );
 }
 
+fn create_or_replace_atomic_modules() {
+    create_or_replace(
+        String::from("./src/atomic/"),
+        REPLACE_ATOMIC,
+        Vec::from(WITH_ATOMIC),
+    );
+}
+
+fn create_or_replace_atomic_tests() {
+    create_or_replace(
+        String::from("./tests/atomic/"),
+        REPLACE_ATOMIC,
+        Vec::from(WITH_ATOMIC),
+    );
+}
+
 fn main() {
     create_or_replace_primitive_tests();
     create_or_replace_primitive_modules();
     create_or_replace_oversized_modules();
     create_or_replace_oversized_tests();
+    create_or_replace_atomic_modules();
+    create_or_replace_atomic_tests();
 }
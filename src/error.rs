@@ -0,0 +1,118 @@
+//! Error types returned by fallible operations across the different bitmaps.
+
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned by fallible operations on a bitmap, such as accessing a bit that's out of
+/// range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitmapError {
+    /// The index is beyond the last bit of the bitmap.
+    IndexOutOfBounds { index: usize, capacity: usize },
+    /// The range from begin (inclusive) to end (exclusive) is backwards, or reaches beyond the
+    /// last bit of the bitmap.
+    RangeOutOfBounds {
+        begin: usize,
+        end: usize,
+        capacity: usize,
+    },
+    /// The range is wider than the operation can handle at once.
+    RangeTooWide { width: usize, max_width: usize },
+    /// The indices given weren't sorted in ascending order.
+    UnsortedIndices { index: usize, previous: usize },
+    /// The slice of bytes is longer than the bitmap's storage. Both lengths are in bytes.
+    SliceTooLong { length: usize, capacity: usize },
+}
+
+impl Display for BitmapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BitmapError::IndexOutOfBounds { index, capacity } => write!(
+                f,
+                "Tried to access bit that's out of range of the bitmap (range: {}, index: {})",
+                capacity, index
+            ),
+            BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity,
+            } => write!(
+                f,
+                "Tried to access range that's out of range of the bitmap (range: {}, begin: {}, end: {})",
+                capacity, begin, end
+            ),
+            BitmapError::RangeTooWide { width, max_width } => write!(
+                f,
+                "Tried to access range that's wider than allowed (width: {}, maximum: {})",
+                width, max_width
+            ),
+            BitmapError::UnsortedIndices { index, previous } => write!(
+                f,
+                "Indices aren't sorted in ascending order (index: {}, previous index: {})",
+                index, previous
+            ),
+            BitmapError::SliceTooLong { length, capacity } => write!(
+                f,
+                "Tried to create bitmap from a slice that's too long for the bitmap (range: {} bytes, length: {})",
+                capacity, length
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitmapError {}
+
+/// The error returned when converting a bitmap into a smaller bitmap fails, because the value has
+/// bits set beyond what the smaller bitmap can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBitmapError {
+    pub(crate) from_length: usize,
+    pub(crate) to_length: usize,
+}
+
+impl Display for TryFromBitmapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Tried to convert a {} bit bitmap into a {} bit bitmap, but it has bits set beyond index {}",
+            self.from_length,
+            self.to_length,
+            self.to_length - 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromBitmapError {}
+
+/// The error returned when parsing a bitmap from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBitmapError {
+    /// The string was empty.
+    Empty,
+    /// The string contained a character that isn't a valid digit, at the given byte index.
+    InvalidDigit { character: char, index: usize },
+    /// The string had more digits than the bitmap can hold.
+    TooLong { length: usize, capacity: usize },
+}
+
+impl Display for ParseBitmapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBitmapError::Empty => write!(f, "Tried to parse bitmap from an empty string"),
+            ParseBitmapError::InvalidDigit { character, index } => write!(
+                f,
+                "Tried to parse bitmap from a string with an invalid digit (character: {:?}, index: {})",
+                character, index
+            ),
+            ParseBitmapError::TooLong { length, capacity } => write!(
+                f,
+                "Tried to parse bitmap from a string that's too long for the bitmap (range: {}, length: {})",
+                capacity, length
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBitmapError {}
@@ -98,20 +98,81 @@
 //!
 //! Note that all of the various `Bitmap` types are exactly the same in
 //! the operations they can perform, the only difference is the integer type they wrap.
+//!
+//! # Features
+//!
+//! - `std` (enabled by default): implements `std::error::Error` for the error types, and prints a
+//!   warning to stderr when arithmetic on the oversized bitmaps overflows. Implies `alloc`.
+//! - `alloc`: enables the methods that return a `String`, such as `to_binary_string_grouped()`.
+//! - `atomic` (enabled by default): enables `AtomicBitmap8`, `AtomicBitmap16`, `AtomicBitmap32`
+//!   and `AtomicBitmap64` on targets with atomics of those widths.
+//!
+//! With default features disabled the crate is `no_std`, only depending on `core`, and can be
+//! used on embedded targets.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod atomic;
+mod error;
 mod oversized;
 mod primitives;
 
+#[cfg(all(feature = "atomic", target_has_atomic = "16"))]
+pub use atomic::AtomicBitmap16;
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
+pub use atomic::AtomicBitmap32;
+#[cfg(all(feature = "atomic", target_has_atomic = "64"))]
+pub use atomic::AtomicBitmap64;
+#[cfg(all(feature = "atomic", target_has_atomic = "8"))]
+pub use atomic::AtomicBitmap8;
+pub use error::BitmapError;
+pub use error::ParseBitmapError;
+pub use error::TryFromBitmapError;
+
 pub use oversized::Bitmap1024;
+pub use oversized::Bitmap1024Bits;
+pub use oversized::Bitmap1024SetBits;
 pub use oversized::Bitmap2048;
+pub use oversized::Bitmap2048Bits;
+pub use oversized::Bitmap2048SetBits;
 pub use oversized::Bitmap256;
+pub use oversized::Bitmap256Bits;
+pub use oversized::Bitmap256SetBits;
 pub use oversized::Bitmap4096;
+pub use oversized::Bitmap4096Bits;
+pub use oversized::Bitmap4096SetBits;
 pub use oversized::Bitmap512;
+pub use oversized::Bitmap512Bits;
+pub use oversized::Bitmap512SetBits;
 pub use oversized::BitmapKB;
+pub use oversized::BitmapKBBits;
+pub use oversized::BitmapKBSetBits;
 pub use primitives::Bitmap128;
+pub use primitives::Bitmap128Bits;
+pub use primitives::Bitmap128ClearBits;
+pub use primitives::Bitmap128SetBits;
 pub use primitives::Bitmap16;
+pub use primitives::Bitmap16Bits;
+pub use primitives::Bitmap16ClearBits;
+pub use primitives::Bitmap16SetBits;
 pub use primitives::Bitmap32;
+pub use primitives::Bitmap32Bits;
+pub use primitives::Bitmap32ClearBits;
+pub use primitives::Bitmap32SetBits;
 pub use primitives::Bitmap64;
+pub use primitives::Bitmap64Bits;
+pub use primitives::Bitmap64ClearBits;
+pub use primitives::Bitmap64SetBits;
 pub use primitives::Bitmap8;
+pub use primitives::Bitmap8Bits;
+pub use primitives::Bitmap8ClearBits;
+pub use primitives::Bitmap8SetBits;
 pub use primitives::BitmapArch;
+pub use primitives::BitmapArchBits;
+pub use primitives::BitmapArchClearBits;
+pub use primitives::BitmapArchSetBits;
+pub use primitives::BitmapOps;
 pub use primitives::BitmapSize;
@@ -1,11 +1,22 @@
 use core::fmt::Formatter;
-use std::{
-    fmt::Display,
+use core::{
+    convert::TryFrom,
+    fmt::{Binary, Display},
     mem,
-    ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, Div,
+        DivAssign, Mul, MulAssign, Not, Sub, SubAssign,
+    },
+    slice, str,
 };
 
-use crate::BitmapSize;
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{BitmapError, BitmapOps, BitmapSize};
 
 const ELEMENT_SIZE: usize = mem::size_of::<usize>() * 8;
 const ELEMENT_COUNT: usize = Bitmap512::MAP_LENGTH / ELEMENT_SIZE;
@@ -34,52 +45,269 @@ impl Bitmap512 {
         self.0
     }
 
-    pub fn get(&self, index: usize) -> Result<bool, String> {
+    /// Views the bitmap's underlying storage as a slice of `MAP_LENGTH / 8` bytes, without
+    /// copying.
+    ///
+    /// The byte ordering is platform-dependent: the elements are laid out in the same order as
+    /// `to_array()` (the element holding the highest bits comes first), and the bytes within
+    /// each element are in the platform's native byte order.
+    pub fn as_bytes(&self) -> &[u8] {
+        // Safety: a `[usize; ELEMENT_COUNT]` is a contiguous region of exactly
+        // `mem::size_of_val(&self.0)` initialised bytes with no padding, and `u8` has no
+        // alignment requirements.
+        unsafe { slice::from_raw_parts(self.0.as_ptr() as *const u8, mem::size_of_val(&self.0)) }
+    }
+
+    /// Views the bitmap's underlying storage as a mutable slice of `MAP_LENGTH / 8` bytes,
+    /// without copying. The byte ordering is the same as for `as_bytes()`.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // Safety: as for `as_bytes()`, and every bit pattern is a valid `usize`, so any bytes
+        // written through the slice leave the bitmap in a valid state.
+        unsafe {
+            slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut u8, mem::size_of_val(&self.0))
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Result<bool, BitmapError> {
         if index >= Bitmap512::MAP_LENGTH {
-            return Err(String::from(
-                "Tried to get bit that's out of range of the bitmap (range: ",
-            ) + &Bitmap512::MAP_LENGTH.to_string()
-                + ", index: "
-                + &index.to_string()
-                + ")");
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap512::MAP_LENGTH,
+            });
         }
 
         let element_location = Bitmap512::get_element_location(index);
-        let mask = 1 << index % ELEMENT_SIZE;
+        let mask = 1 << (index % ELEMENT_SIZE);
         Ok(self.0[element_location] & mask > 0)
     }
 
-    pub fn set(&mut self, index: usize, value: bool) -> Result<(), String> {
+    /// Gets the bits from begin (inclusive) to end (exclusive) packed into the low bits of a
+    /// `u64`, with bit 0 of the result being the bit at `begin`. The range may span several
+    /// elements.
+    ///
+    /// Returns an `Err<BitmapError>` if `begin > end`, if `end` is beyond the bitmap's length,
+    /// or if the range is wider than 64 bits.
+    pub fn get_range(&self, begin: usize, end: usize) -> Result<u64, BitmapError> {
+        if begin > end || end > Bitmap512::MAP_LENGTH {
+            return Err(BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity: Bitmap512::MAP_LENGTH,
+            });
+        }
+
+        let width = end - begin;
+        if width > 64 {
+            return Err(BitmapError::RangeTooWide {
+                width,
+                max_width: 64,
+            });
+        }
+
+        let mut value = 0;
+        let mut read = 0;
+        while read < width {
+            let index = begin + read;
+            let element = self.0[Bitmap512::get_element_location(index)] >> (index % ELEMENT_SIZE);
+            let taken = (ELEMENT_SIZE - index % ELEMENT_SIZE).min(width - read);
+
+            value |= (element as u64 & (u64::MAX >> (64 - taken))) << read;
+            read += taken;
+        }
+
+        Ok(value)
+    }
+
+    /// Writes the low `end - begin` bits of `value` into the bitmap from begin (inclusive) to
+    /// end (exclusive), with bit 0 of `value` going to `begin`. Any bits of `value` above the
+    /// width of the range are ignored. This is the inverse of `get_range()`.
+    ///
+    /// Returns an `Err<BitmapError>` if `begin > end`, if `end` is beyond the bitmap's length,
+    /// or if the range is wider than 64 bits, in which case the bitmap's state remains
+    /// unchanged.
+    pub fn set_bits_from_value(
+        &mut self,
+        begin: usize,
+        end: usize,
+        value: u64,
+    ) -> Result<(), BitmapError> {
+        if begin > end || end > Bitmap512::MAP_LENGTH {
+            return Err(BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity: Bitmap512::MAP_LENGTH,
+            });
+        }
+
+        let width = end - begin;
+        if width > 64 {
+            return Err(BitmapError::RangeTooWide {
+                width,
+                max_width: 64,
+            });
+        }
+
+        let mut written = 0;
+        while written < width {
+            let index = begin + written;
+            let offset = index % ELEMENT_SIZE;
+            let taken = (ELEMENT_SIZE - offset).min(width - written);
+
+            let mask = (usize::MAX >> (ELEMENT_SIZE - taken)) << offset;
+            let bits = ((value >> written) as usize) << offset;
+            let element = &mut self.0[Bitmap512::get_element_location(index)];
+            *element = (*element & !mask) | (bits & mask);
+
+            written += taken;
+        }
+
+        Ok(())
+    }
+
+    /// Gets the raw element holding bits `word_index * ELEMENT_SIZE` (inclusive) to
+    /// `(word_index + 1) * ELEMENT_SIZE` (exclusive), where `ELEMENT_SIZE` is the number of
+    /// bits in a `usize`. Word 0 holds the lowest bits of the bitmap.
+    ///
+    /// Returns an `Err<BitmapError>` if the word index is beyond the number of elements.
+    pub fn get_chunk(&self, word_index: usize) -> Result<usize, BitmapError> {
+        if word_index >= ELEMENT_COUNT {
+            return Err(BitmapError::IndexOutOfBounds {
+                index: word_index,
+                capacity: ELEMENT_COUNT,
+            });
+        }
+
+        Ok(self.0[ELEMENT_COUNT - 1 - word_index])
+    }
+
+    /// Overwrites the raw element at the given word index with `value`. Words are indexed the
+    /// same way as for `get_chunk()`.
+    ///
+    /// Returns an `Err<BitmapError>` if the word index is beyond the number of elements, in
+    /// which case the bitmap's state remains unchanged.
+    pub fn set_chunk(&mut self, word_index: usize, value: usize) -> Result<(), BitmapError> {
+        if word_index >= ELEMENT_COUNT {
+            return Err(BitmapError::IndexOutOfBounds {
+                index: word_index,
+                capacity: ELEMENT_COUNT,
+            });
+        }
+
+        self.0[ELEMENT_COUNT - 1 - word_index] = value;
+        Ok(())
+    }
+
+    /// Gets the bit at the given index without checking that the index is in bounds.
+    ///
+    /// ## Safety
+    ///
+    /// Calling this with `index >= Bitmap512::capacity()` is undefined behaviour, as it reads
+    /// outside of the underlying array.
+    pub unsafe fn get_unchecked(&self, index: usize) -> bool {
+        debug_assert!(index < Bitmap512::MAP_LENGTH);
+        let element_location = Bitmap512::get_element_location(index);
+        self.0.get_unchecked(element_location) & (1 << (index % ELEMENT_SIZE)) > 0
+    }
+
+    /// Sets the bit at the given index to the value provided without checking that the index
+    /// is in bounds.
+    ///
+    /// ## Safety
+    ///
+    /// Calling this with `index >= Bitmap512::capacity()` is undefined behaviour, as it writes
+    /// outside of the underlying array.
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: bool) {
+        debug_assert!(index < Bitmap512::MAP_LENGTH);
+        let element_location = Bitmap512::get_element_location(index);
+        let element = self.0.get_unchecked_mut(element_location);
+        if value {
+            *element |= 1 << (index % ELEMENT_SIZE);
+        } else {
+            *element &= !(1 << (index % ELEMENT_SIZE));
+        }
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), BitmapError> {
         if index >= Bitmap512::MAP_LENGTH {
-            return Err(String::from(
-                "Tried to set bit that's out of range of the bitmap (range: ",
-            ) + &Bitmap512::MAP_LENGTH.to_string()
-                + ", index: "
-                + &index.to_string()
-                + ")");
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap512::MAP_LENGTH,
+            });
         }
 
         let element_location = Bitmap512::get_element_location(index);
 
         if value {
-            let mask = 1 << index % ELEMENT_SIZE;
+            let mask = 1 << (index % ELEMENT_SIZE);
             self.0[element_location] |= mask;
         } else {
-            let mask = usize::MAX - (1 << index % ELEMENT_SIZE);
+            let mask = usize::MAX - (1 << (index % ELEMENT_SIZE));
             self.0[element_location] &= mask;
         }
 
         Ok(())
     }
 
-    pub fn from_set(index: usize) -> Option<Bitmap512> {
+    /// Flips the bit at the given index, returning an `Err<BitmapError>` if the index is out
+    /// of bounds, in which case the bitmap's state remains unchanged.
+    pub fn toggle(&mut self, index: usize) -> Result<(), BitmapError> {
         if index >= Bitmap512::MAP_LENGTH {
-            return None;
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap512::MAP_LENGTH,
+            });
+        }
+
+        let element_location = Bitmap512::get_element_location(index);
+        self.0[element_location] ^= 1 << (index % ELEMENT_SIZE);
+
+        Ok(())
+    }
+
+    /// Sets the bits from begin (inclusive) to end (exclusive) to the given value. The elements
+    /// entirely inside the range are filled directly, with the elements at either edge being
+    /// partially masked.
+    ///
+    /// Returns an `Err<BitmapError>` if `begin > end` or `end` is beyond the bitmap's length, in
+    /// which case the bitmap's state remains unchanged.
+    pub fn set_range(&mut self, begin: usize, end: usize, value: bool) -> Result<(), BitmapError> {
+        if begin > end || end > Bitmap512::MAP_LENGTH {
+            return Err(BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity: Bitmap512::MAP_LENGTH,
+            });
+        }
+
+        super::set_range_elements(&mut self.0, begin, end, value);
+        Ok(())
+    }
+
+    /// Flips the bits from begin (inclusive) to end (exclusive). Only the elements the range
+    /// covers are touched, with the elements at either edge being partially masked.
+    pub fn toggle_range(&mut self, begin: usize, end: usize) {
+        let end = end.min(Bitmap512::MAP_LENGTH);
+        if begin < end {
+            super::toggle_range_elements(&mut self.0, begin, end);
         }
+    }
+
+    /// Swaps the bits at indices `i` and `j`. Nothing is written if both bits are equal.
+    /// Returns an `Err<BitmapError>` if either index is out of bounds, in which case the bitmap's
+    /// state remains unchanged.
+    pub fn swap_bits(&mut self, i: usize, j: usize) -> Result<(), BitmapError> {
+        if self.get(i)? != self.get(j)? {
+            self.toggle(i)?;
+            self.toggle(j)?;
+        }
+
+        Ok(())
+    }
 
+    pub fn from_set(index: usize) -> Result<Bitmap512, BitmapError> {
         let mut bitmap = Bitmap512::default();
-        bitmap.set(index, true).unwrap();
-        Some(bitmap)
+        bitmap.set(index, true)?;
+        Ok(bitmap)
     }
 
     pub fn new(value: bool) -> Bitmap512 {
@@ -89,18 +317,247 @@ impl Bitmap512 {
             [0; ELEMENT_COUNT]
         })
     }
+
+    /// Create a new bitmap that has its bits set from `begin` (inclusive) to `end` (exclusive),
+    /// with every other bit set to the opposite of value. An `end` beyond the map length is
+    /// treated as the map length, and an empty range gives a bitmap with all bits set to the
+    /// opposite of value.
+    pub fn create_bit_mask(begin: usize, end: usize, value: bool) -> Bitmap512 {
+        let mut bitmap = Bitmap512::new(!value);
+        let end = end.min(Bitmap512::MAP_LENGTH);
+        if begin < end {
+            super::set_range_elements(&mut bitmap.0, begin, end, value);
+        }
+        bitmap
+    }
+
+    /// Reverses the order of the bits, so the bit at index 0 ends up at the highest index and
+    /// vice versa. This reverses the order of the elements, as well as the bits within each one.
+    pub fn reverse_bits(&self) -> Bitmap512 {
+        let mut bitmap = self.0;
+        bitmap.reverse();
+        for element in bitmap.iter_mut() {
+            *element = element.reverse_bits();
+        }
+        Bitmap512(bitmap)
+    }
+
+    /// Returns an iterator over the indices of all set bits, in ascending order. Each element
+    /// is only visited once, with its set bits popped off one at a time.
+    pub fn set_bits(&self) -> Bitmap512SetBits {
+        Bitmap512SetBits {
+            bitmap: *self,
+            word: 0,
+        }
+    }
+
+    /// Returns an iterator over every bit as a `bool`, starting from index 0.
+    pub fn bits(&self) -> Bitmap512Bits {
+        Bitmap512Bits {
+            bitmap: *self,
+            index: 0,
+        }
+    }
+
+    /// Counts the number of bits that are set.
+    pub fn count_ones(&self) -> usize {
+        self.0
+            .iter()
+            .map(|element| element.count_ones() as usize)
+            .sum()
+    }
+
+    /// Counts the number of bits that are cleared.
+    pub fn count_zeros(&self) -> usize {
+        Bitmap512::MAP_LENGTH - self.count_ones()
+    }
+
+    /// Returns the parity of the bitmap, which is the XOR of all of its bits. In other words,
+    /// true if an odd number of bits are set.
+    pub fn parity(&self) -> bool {
+        let folded = self.0.iter().fold(0, |folded, element| folded ^ element);
+        folded.count_ones() & 1 == 1
+    }
+
+    /// Counts the number of positions at which the bits of the two bitmaps differ.
+    pub fn hamming_distance(&self, other: &Bitmap512) -> usize {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns true if every bit set in this bitmap is also set in `other`, stopping at the first
+    /// element where that isn't the case.
+    pub fn is_subset(&self, other: &Bitmap512) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a & b == *a)
+    }
+
+    /// Returns true if every bit set in `other` is also set in this bitmap.
+    pub fn is_superset(&self, other: &Bitmap512) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns true if the two bitmaps have no set bits in common, stopping at the first element
+    /// where they do.
+    pub fn is_disjoint(&self, other: &Bitmap512) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a & b == 0)
+    }
+
+    /// Returns a bitmap of the bits set in this bitmap but not in `other`. This is the same as
+    /// `bitmap & !other`.
+    pub fn difference(&self, other: &Bitmap512) -> Bitmap512 {
+        let mut bitmap = self.0;
+        for (element, other_element) in bitmap.iter_mut().zip(other.0.iter()) {
+            *element &= !other_element;
+        }
+        Bitmap512(bitmap)
+    }
+
+    /// Returns true if no bits are set, stopping at the first element with a bit set.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&element| element == 0)
+    }
+
+    /// Returns true if any bit is set. This is the opposite of `is_empty()`.
+    pub fn any(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Returns true if every bit is set, stopping at the first element with a bit cleared.
+    pub fn is_full(&self) -> bool {
+        self.0.iter().all(|&element| element == usize::MAX)
+    }
+
+    /// Counts the number of cleared bits above the highest set bit. Elements are scanned from
+    /// the high end, stopping at the first one with a bit set. Returns `MAP_LENGTH` if no bits
+    /// are set.
+    pub fn leading_zeros(&self) -> usize {
+        let mut zeros = 0;
+        for &element in self.0.iter() {
+            zeros += element.leading_zeros() as usize;
+            if element != 0 {
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// Counts the number of cleared bits below the lowest set bit. Elements are scanned from
+    /// the low end, stopping at the first one with a bit set. Returns `MAP_LENGTH` if no bits
+    /// are set.
+    pub fn trailing_zeros(&self) -> usize {
+        let mut zeros = 0;
+        for &element in self.0.iter().rev() {
+            zeros += element.trailing_zeros() as usize;
+            if element != 0 {
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// Finds the index of the lowest set bit, or `None` if no bits are set.
+    pub fn find_first_set(&self) -> Option<usize> {
+        match self.trailing_zeros() {
+            Bitmap512::MAP_LENGTH => None,
+            zeros => Some(zeros),
+        }
+    }
+
+    /// Finds the index of the highest set bit, or `None` if no bits are set.
+    pub fn find_last_set(&self) -> Option<usize> {
+        match self.leading_zeros() {
+            Bitmap512::MAP_LENGTH => None,
+            zeros => Some(Bitmap512::MAP_LENGTH - 1 - zeros),
+        }
+    }
+
+    /// Finds the index of the lowest cleared bit, or `None` if every bit is set.
+    pub fn find_first_clear(&self) -> Option<usize> {
+        for (word, &element) in self.0.iter().rev().enumerate() {
+            if element != usize::MAX {
+                return Some(word * ELEMENT_SIZE + (!element).trailing_zeros() as usize);
+            }
+        }
+
+        None
+    }
+
+    /// Finds the index of the highest cleared bit, or `None` if every bit is set.
+    pub fn find_last_clear(&self) -> Option<usize> {
+        for (location, &element) in self.0.iter().enumerate() {
+            if element != usize::MAX {
+                let word = ELEMENT_COUNT - 1 - location;
+                return Some(
+                    word * ELEMENT_SIZE + ELEMENT_SIZE - 1 - (!element).leading_zeros() as usize,
+                );
+            }
+        }
+
+        None
+    }
+
+    /// Finds the index of the lowest set bit strictly after the index `after`, or `None` if
+    /// there are no set bits beyond that point. Out-of-bounds values of `after` return `None`.
+    /// Elements entirely at or below `after` are skipped without being scanned.
+    pub fn find_next_set(&self, after: usize) -> Option<usize> {
+        if after >= Bitmap512::MAP_LENGTH - 1 {
+            return None;
+        }
+
+        let start = after + 1;
+        let first_word = start / ELEMENT_SIZE;
+
+        for word in first_word..ELEMENT_COUNT {
+            let mut element = self.0[ELEMENT_COUNT - 1 - word];
+            if word == first_word {
+                element &= usize::MAX << (start % ELEMENT_SIZE);
+            }
+
+            if element != 0 {
+                return Some(word * ELEMENT_SIZE + element.trailing_zeros() as usize);
+            }
+        }
+
+        None
+    }
 }
 
 impl Display for Bitmap512 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        let mut bitmap = String::new();
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         for i in 0..ELEMENT_COUNT {
-            bitmap.push_str(format!("{:X}", self.0[i]).as_str());
+            write!(f, "{:X}", self.0[i])?;
             if i < ELEMENT_COUNT - 1 {
-                bitmap.push_str("_");
+                write!(f, "_")?;
             }
         }
-        write!(f, "{}", bitmap.chars().collect::<String>())
+        Ok(())
+    }
+}
+
+/// Formats the bitmap's value in binary without leading zeros, as the integer types do. Flags
+/// and widths are respected, so `format!("{:0w$b}", bitmap, w = Bitmap512::MAP_LENGTH)` shows
+/// every bit.
+impl Binary for Bitmap512 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // Written from the least significant bit backwards, so the leading zeros can be
+        // skipped by starting the slice at the highest set bit.
+        let mut digits = [b'0'; Bitmap512::MAP_LENGTH];
+        for (i, digit) in digits.iter_mut().rev().enumerate() {
+            if self.0[Bitmap512::get_element_location(i)] & (1 << (i % ELEMENT_SIZE)) > 0 {
+                *digit = b'1';
+            }
+        }
+
+        let start = self
+            .find_last_set()
+            .map_or(Bitmap512::MAP_LENGTH - 1, |last| {
+                Bitmap512::MAP_LENGTH - 1 - last
+            });
+        // `digits` only ever contains the ASCII characters `0` and `1`, so this can't fail.
+        f.pad_integral(true, "0b", str::from_utf8(&digits[start..]).unwrap())
     }
 }
 
@@ -108,12 +565,58 @@ impl BitmapSize for Bitmap512 {
     const MAP_LENGTH: usize = 512;
 }
 
+impl BitmapOps for Bitmap512 {
+    fn get(&self, index: usize) -> Result<bool, BitmapError> {
+        Bitmap512::get(self, index)
+    }
+
+    fn set(&mut self, index: usize, value: bool) -> Result<(), BitmapError> {
+        Bitmap512::set(self, index, value)
+    }
+
+    fn count_ones(&self) -> usize {
+        Bitmap512::count_ones(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        Bitmap512::is_empty(self)
+    }
+
+    fn is_full(&self) -> bool {
+        Bitmap512::is_full(self)
+    }
+}
+
 impl From<[usize; ELEMENT_COUNT]> for Bitmap512 {
     fn from(value: [usize; ELEMENT_COUNT]) -> Self {
         Bitmap512(value)
     }
 }
 
+/// Interprets the bytes as raw bitmap storage, in the same platform-dependent order that
+/// `as_bytes()` produces, so that `Bitmap512::try_from(bitmap.as_bytes())` gives back `bitmap`.
+///
+/// A slice shorter than `MAP_LENGTH / 8` bytes fills the start of the storage, with every byte
+/// after it left as 0. A slice longer than that returns an `Err<BitmapError>`.
+impl TryFrom<&[u8]> for Bitmap512 {
+    type Error = BitmapError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let mut bitmap = Bitmap512::default();
+        let storage = bitmap.as_bytes_mut();
+
+        if bytes.len() > storage.len() {
+            return Err(BitmapError::SliceTooLong {
+                length: bytes.len(),
+                capacity: storage.len(),
+            });
+        }
+
+        storage[..bytes.len()].copy_from_slice(bytes);
+        Ok(bitmap)
+    }
+}
+
 // Traits implementing bitwise operations between Bitmaps of the same type
 
 impl BitAnd for Bitmap512 {
@@ -121,8 +624,8 @@ impl BitAnd for Bitmap512 {
 
     fn bitand(self, rhs: Self) -> Self::Output {
         let mut bitmap = self.0;
-        for i in 0..ELEMENT_COUNT {
-            bitmap[i] &= rhs.0[i];
+        for (element, rhs_element) in bitmap.iter_mut().zip(rhs.0.iter()) {
+            *element &= rhs_element;
         }
         Self(bitmap)
     }
@@ -130,8 +633,8 @@ impl BitAnd for Bitmap512 {
 
 impl BitAndAssign for Bitmap512 {
     fn bitand_assign(&mut self, rhs: Self) {
-        for i in 0..ELEMENT_COUNT {
-            self.0[i] &= rhs.0[i];
+        for (element, rhs_element) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *element &= rhs_element;
         }
     }
 }
@@ -141,8 +644,8 @@ impl BitOr for Bitmap512 {
 
     fn bitor(self, rhs: Self) -> Self::Output {
         let mut bitmap = self.0;
-        for i in 0..ELEMENT_COUNT {
-            bitmap[i] |= rhs.0[i];
+        for (element, rhs_element) in bitmap.iter_mut().zip(rhs.0.iter()) {
+            *element |= rhs_element;
         }
         Self(bitmap)
     }
@@ -150,8 +653,8 @@ impl BitOr for Bitmap512 {
 
 impl BitOrAssign for Bitmap512 {
     fn bitor_assign(&mut self, rhs: Self) {
-        for i in 0..ELEMENT_COUNT {
-            self.0[i] |= rhs.0[i];
+        for (element, rhs_element) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *element |= rhs_element;
         }
     }
 }
@@ -161,8 +664,8 @@ impl BitXor for Bitmap512 {
 
     fn bitxor(self, rhs: Self) -> Self::Output {
         let mut bitmap = self.0;
-        for i in 0..ELEMENT_COUNT {
-            bitmap[i] ^= rhs.0[i];
+        for (element, rhs_element) in bitmap.iter_mut().zip(rhs.0.iter()) {
+            *element ^= rhs_element;
         }
         Self(bitmap)
     }
@@ -170,12 +673,24 @@ impl BitXor for Bitmap512 {
 
 impl BitXorAssign for Bitmap512 {
     fn bitxor_assign(&mut self, rhs: Self) {
-        for i in 0..ELEMENT_COUNT {
-            self.0[i] ^= rhs.0[i];
+        for (element, rhs_element) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *element ^= rhs_element;
         }
     }
 }
 
+impl Not for Bitmap512 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        let mut bitmap = self.0;
+        for element in bitmap.iter_mut() {
+            *element = !*element;
+        }
+        Self(bitmap)
+    }
+}
+
 // Traits implementing bitwise operations between Bitmaps and their respective array type
 
 impl BitAnd<[usize; ELEMENT_COUNT]> for Bitmap512 {
@@ -183,8 +698,8 @@ impl BitAnd<[usize; ELEMENT_COUNT]> for Bitmap512 {
 
     fn bitand(self, rhs: [usize; ELEMENT_COUNT]) -> Self::Output {
         let mut bitmap = self.0;
-        for i in 0..ELEMENT_COUNT {
-            bitmap[i] &= rhs[i];
+        for (element, rhs_element) in bitmap.iter_mut().zip(rhs.iter()) {
+            *element &= rhs_element;
         }
         Self(bitmap)
     }
@@ -192,8 +707,8 @@ impl BitAnd<[usize; ELEMENT_COUNT]> for Bitmap512 {
 
 impl BitAndAssign<[usize; ELEMENT_COUNT]> for Bitmap512 {
     fn bitand_assign(&mut self, rhs: [usize; ELEMENT_COUNT]) {
-        for i in 0..ELEMENT_COUNT {
-            self.0[i] &= rhs[i];
+        for (element, rhs_element) in self.0.iter_mut().zip(rhs.iter()) {
+            *element &= rhs_element;
         }
     }
 }
@@ -203,8 +718,8 @@ impl BitOr<[usize; ELEMENT_COUNT]> for Bitmap512 {
 
     fn bitor(self, rhs: [usize; ELEMENT_COUNT]) -> Self::Output {
         let mut bitmap = self.0;
-        for i in 0..ELEMENT_COUNT {
-            bitmap[i] |= rhs[i];
+        for (element, rhs_element) in bitmap.iter_mut().zip(rhs.iter()) {
+            *element |= rhs_element;
         }
         Self(bitmap)
     }
@@ -212,8 +727,8 @@ impl BitOr<[usize; ELEMENT_COUNT]> for Bitmap512 {
 
 impl BitOrAssign<[usize; ELEMENT_COUNT]> for Bitmap512 {
     fn bitor_assign(&mut self, rhs: [usize; ELEMENT_COUNT]) {
-        for i in 0..ELEMENT_COUNT {
-            self.0[i] |= rhs[i];
+        for (element, rhs_element) in self.0.iter_mut().zip(rhs.iter()) {
+            *element |= rhs_element;
         }
     }
 }
@@ -223,8 +738,8 @@ impl BitXor<[usize; ELEMENT_COUNT]> for Bitmap512 {
 
     fn bitxor(self, rhs: [usize; ELEMENT_COUNT]) -> Self::Output {
         let mut bitmap = self.0;
-        for i in 0..ELEMENT_COUNT {
-            bitmap[i] ^= rhs[i];
+        for (element, rhs_element) in bitmap.iter_mut().zip(rhs.iter()) {
+            *element ^= rhs_element;
         }
         Self(bitmap)
     }
@@ -232,8 +747,8 @@ impl BitXor<[usize; ELEMENT_COUNT]> for Bitmap512 {
 
 impl BitXorAssign<[usize; ELEMENT_COUNT]> for Bitmap512 {
     fn bitxor_assign(&mut self, rhs: [usize; ELEMENT_COUNT]) {
-        for i in 0..ELEMENT_COUNT {
-            self.0[i] ^= rhs[i];
+        for (element, rhs_element) in self.0.iter_mut().zip(rhs.iter()) {
+            *element ^= rhs_element;
         }
     }
 }
@@ -259,6 +774,7 @@ impl Add<usize> for Bitmap512 {
         }
 
         if carry > 0 {
+            #[cfg(feature = "std")]
             eprintln!("Warning: Adding led to overflow!");
         }
 
@@ -282,11 +798,114 @@ impl AddAssign<usize> for Bitmap512 {
         }
 
         if carry > 0 {
+            #[cfg(feature = "std")]
             eprintln!("Warning: Adding led to overflow!");
         }
     }
 }
 
+impl Sub<usize> for Bitmap512 {
+    type Output = Self;
+
+    fn sub(self, rhs: usize) -> Self::Output {
+        let mut bitmap = self.0;
+        let mut borrow = rhs;
+
+        for i in (0..ELEMENT_COUNT).rev() {
+            if bitmap[i] < borrow {
+                bitmap[i] = bitmap[i].wrapping_sub(borrow);
+                borrow = 1;
+            } else {
+                bitmap[i] -= borrow;
+                borrow = 0;
+                break;
+            }
+        }
+
+        if borrow > 0 {
+            #[cfg(feature = "std")]
+            eprintln!("Warning: Subtracting led to underflow!");
+        }
+
+        Self(bitmap)
+    }
+}
+
+impl SubAssign<usize> for Bitmap512 {
+    fn sub_assign(&mut self, rhs: usize) {
+        let mut borrow = rhs;
+
+        for i in (0..ELEMENT_COUNT).rev() {
+            if self.0[i] < borrow {
+                self.0[i] = self.0[i].wrapping_sub(borrow);
+                borrow = 1;
+            } else {
+                self.0[i] -= borrow;
+                borrow = 0;
+                break;
+            }
+        }
+
+        if borrow > 0 {
+            #[cfg(feature = "std")]
+            eprintln!("Warning: Subtracting led to underflow!");
+        }
+    }
+}
+
+impl Mul<usize> for Bitmap512 {
+    type Output = Self;
+
+    fn mul(mut self, rhs: usize) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl MulAssign<usize> for Bitmap512 {
+    /// Long multiplication, working from the least significant element up and carrying the upper
+    /// half of each element's product into the next.
+    fn mul_assign(&mut self, rhs: usize) {
+        let mut carry = 0;
+
+        for element in self.0.iter_mut().rev() {
+            let product = *element as u128 * rhs as u128 + carry;
+            *element = product as usize;
+            carry = product >> ELEMENT_SIZE;
+        }
+
+        if carry > 0 {
+            #[cfg(feature = "std")]
+            eprintln!("Warning: Multiplying led to overflow!");
+        }
+    }
+}
+
+impl Div<usize> for Bitmap512 {
+    type Output = Self;
+
+    fn div(mut self, rhs: usize) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl DivAssign<usize> for Bitmap512 {
+    /// Long division, working from the most significant element down and carrying the remainder
+    /// of each element into the next. Panics if `rhs` is 0, as the integer types do.
+    fn div_assign(&mut self, rhs: usize) {
+        let mut remainder = 0;
+
+        for element in self.0.iter_mut() {
+            let dividend = (remainder << ELEMENT_SIZE) | *element as u128;
+            *element = (dividend / rhs as u128) as usize;
+            remainder = dividend % rhs as u128;
+        }
+    }
+}
+
+impl_shifts!(Bitmap512);
+
 impl Deref for Bitmap512 {
     type Target = [usize; ELEMENT_COUNT];
 
@@ -295,26 +914,215 @@ impl Deref for Bitmap512 {
     }
 }
 
-// An attempt at serialization so far, no idea how to implement deserialisation yet
-//
-// impl Serialize for Bitmap512 {
-//     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: Serializer,
-//     {
-//         let mut seq = serializer.serialize_seq(Some(NUM_ELEMENTS))?;
-//         for e in self.0 {
-//             seq.serialize_element(&e)?;
-//         }
-//         seq.end()
-//     }
-// }
+// Iterators
+
+/// An iterator over every bit of a `Bitmap512` as a `bool`, starting from index 0.
+///
+/// Created by `Bitmap512::bits()`.
+#[derive(Clone, Debug)]
+pub struct Bitmap512Bits {
+    bitmap: Bitmap512,
+    index: usize,
+}
+
+impl Iterator for Bitmap512Bits {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bit = self.bitmap.get(self.index).ok()?;
+        self.index += 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = Bitmap512::MAP_LENGTH - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bitmap512Bits {}
+
+/// An iterator over the indices of the set bits of a `Bitmap512`, in ascending order.
+///
+/// Created by `Bitmap512::set_bits()`.
+#[derive(Clone, Debug)]
+pub struct Bitmap512SetBits {
+    bitmap: Bitmap512,
+    word: usize,
+}
+
+impl Iterator for Bitmap512SetBits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word < ELEMENT_COUNT {
+            let element = &mut self.bitmap.0[ELEMENT_COUNT - 1 - self.word];
+            if *element != 0 {
+                let index = self.word * ELEMENT_SIZE + element.trailing_zeros() as usize;
+                // Clears the lowest set bit
+                *element &= *element - 1;
+                return Some(index);
+            }
+            self.word += 1;
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bitmap.count_ones();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bitmap512SetBits {}
+
+impl IntoIterator for Bitmap512 {
+    type Item = bool;
+    type IntoIter = Bitmap512Bits;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits()
+    }
+}
+
+impl IntoIterator for &Bitmap512 {
+    type Item = bool;
+    type IntoIter = Bitmap512Bits;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits()
+    }
+}
+
+// Serialization. Bitmaps of 1_024 bits or more get a compact hex string of the bitmap's value,
+// most significant digit first, in human-readable formats such as JSON. Every other case gets a
+// sequence of exactly `ELEMENT_COUNT` elements in the same order as the underlying array, which
+// keeps the smaller bitmaps in the same format in every serializer.
+
+/// Whether human-readable formats use a hex string rather than a sequence of elements.
+const HEX_WHEN_HUMAN_READABLE: bool = Bitmap512::MAP_LENGTH >= 1_024;
+
+impl Serialize for Bitmap512 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if HEX_WHEN_HUMAN_READABLE && serializer.is_human_readable() {
+            serializer.collect_str(&Bitmap512Hex(self))
+        } else {
+            let mut seq = serializer.serialize_seq(Some(ELEMENT_COUNT))?;
+            for element in self.0.iter() {
+                seq.serialize_element(element)?;
+            }
+            seq.end()
+        }
+    }
+}
+
+/// Displays the bitmap as a lowercase hex string with every digit shown, for serializing
+/// without needing an allocator.
+struct Bitmap512Hex<'a>(&'a Bitmap512);
+
+impl Display for Bitmap512Hex<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for element in self.0 .0.iter() {
+            write!(f, "{:0width$x}", element, width = ELEMENT_SIZE / 4)?;
+        }
+        Ok(())
+    }
+}
+
+struct Bitmap512Visitor;
+
+impl<'de> Visitor<'de> for Bitmap512Visitor {
+    type Value = Bitmap512;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        if HEX_WHEN_HUMAN_READABLE {
+            write!(
+                formatter,
+                "a hex string of at most {} digits or a sequence of {} elements",
+                Bitmap512::MAP_LENGTH / 4,
+                ELEMENT_COUNT
+            )
+        } else {
+            write!(formatter, "a sequence of {} elements", ELEMENT_COUNT)
+        }
+    }
+
+    /// Parses a hex string with an optional `0x` or `0X` prefix. Strings with fewer digits than the
+    /// bitmap can hold are padded with leading zeros.
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let digits = v
+            .strip_prefix("0x")
+            .or_else(|| v.strip_prefix("0X"))
+            .unwrap_or(v);
+        if digits.is_empty()
+            || digits.len() > Bitmap512::MAP_LENGTH / 4
+            || !digits
+                .chars()
+                .all(|character| character.is_ascii_hexdigit())
+        {
+            return Err(de::Error::invalid_value(de::Unexpected::Str(v), &self));
+        }
+
+        let digits_per_element = ELEMENT_SIZE / 4;
+        let mut bitmap = [0; ELEMENT_COUNT];
+        for (i, element) in bitmap.iter_mut().rev().enumerate() {
+            let end = digits.len().saturating_sub(i * digits_per_element);
+            let start = end.saturating_sub(digits_per_element);
+            if start == end {
+                break;
+            }
+
+            *element = usize::from_str_radix(&digits[start..end], 16)
+                .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(v), &self))?;
+        }
+
+        Ok(Bitmap512(bitmap))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bitmap = [0; ELEMENT_COUNT];
+        for (i, element) in bitmap.iter_mut().enumerate() {
+            *element = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        if seq.next_element::<usize>()?.is_some() {
+            return Err(de::Error::invalid_length(ELEMENT_COUNT + 1, &self));
+        }
+
+        Ok(Bitmap512(bitmap))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bitmap512 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if HEX_WHEN_HUMAN_READABLE && deserializer.is_human_readable() {
+            deserializer.deserialize_str(Bitmap512Visitor)
+        } else {
+            deserializer.deserialize_seq(Bitmap512Visitor)
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::BitmapSize;
     use super::{Bitmap512, ELEMENT_COUNT, ELEMENT_SIZE};
-    use std::mem;
+    use core::mem;
 
     #[test]
     fn create_default() {
@@ -1,3 +1,134 @@
+use core::mem;
+
+const ELEMENT_SIZE: usize = mem::size_of::<usize>() * 8;
+
+/// Shifts the bits held in `elements` left by `n`, filling with zeros. As the element holding the
+/// lowest bits is stored last, this moves bits towards the start of the slice, carrying the
+/// overflow of each element into the one before it.
+fn shift_elements_left(elements: &mut [usize], n: usize) {
+    let count = elements.len();
+    let element_shift = n / ELEMENT_SIZE;
+    let bit_shift = n % ELEMENT_SIZE;
+
+    for i in 0..count {
+        let source = i + element_shift;
+        elements[i] = if source >= count {
+            0
+        } else if bit_shift == 0 || source + 1 >= count {
+            elements[source] << bit_shift
+        } else {
+            (elements[source] << bit_shift) | (elements[source + 1] >> (ELEMENT_SIZE - bit_shift))
+        };
+    }
+}
+
+/// Shifts the bits held in `elements` right by `n`, filling with zeros. This is the mirror of
+/// `shift_elements_left`, moving bits towards the end of the slice.
+fn shift_elements_right(elements: &mut [usize], n: usize) {
+    let count = elements.len();
+    let element_shift = n / ELEMENT_SIZE;
+    let bit_shift = n % ELEMENT_SIZE;
+
+    for i in (0..count).rev() {
+        elements[i] = if i < element_shift {
+            0
+        } else if bit_shift == 0 || i == element_shift {
+            elements[i - element_shift] >> bit_shift
+        } else {
+            (elements[i - element_shift] >> bit_shift)
+                | (elements[i - element_shift - 1] << (ELEMENT_SIZE - bit_shift))
+        };
+    }
+}
+
+/// Gets the mask of the bits within the `word`th element (counting from the low end) that fall
+/// between begin (inclusive) and end (exclusive). Assumes `begin < end`.
+fn word_mask(word: usize, begin: usize, end: usize) -> usize {
+    let low = if word == begin / ELEMENT_SIZE {
+        begin % ELEMENT_SIZE
+    } else {
+        0
+    };
+    let high = if word == (end - 1) / ELEMENT_SIZE {
+        (end - 1) % ELEMENT_SIZE + 1
+    } else {
+        ELEMENT_SIZE
+    };
+
+    (usize::MAX << low) & (usize::MAX >> (ELEMENT_SIZE - high))
+}
+
+/// Sets the bits held in `elements` from begin (inclusive) to end (exclusive) to the given value.
+/// The elements at either edge of the range are partially masked, while the elements between them
+/// are filled outright. Assumes `begin <= end <= elements.len() * ELEMENT_SIZE`.
+fn set_range_elements(elements: &mut [usize], begin: usize, end: usize, value: bool) {
+    if begin == end {
+        return;
+    }
+
+    let count = elements.len();
+    let first_word = begin / ELEMENT_SIZE;
+    let last_word = (end - 1) / ELEMENT_SIZE;
+    let fill = if value { usize::MAX } else { 0 };
+
+    for word in first_word..=last_word {
+        let element = &mut elements[count - 1 - word];
+        if word != first_word && word != last_word {
+            *element = fill;
+        } else if value {
+            *element |= word_mask(word, begin, end);
+        } else {
+            *element &= !word_mask(word, begin, end);
+        }
+    }
+}
+
+/// Flips the bits held in `elements` from begin (inclusive) to end (exclusive). Assumes
+/// `begin < end <= elements.len() * ELEMENT_SIZE`.
+fn toggle_range_elements(elements: &mut [usize], begin: usize, end: usize) {
+    let count = elements.len();
+    for word in begin / ELEMENT_SIZE..=(end - 1) / ELEMENT_SIZE {
+        elements[count - 1 - word] ^= word_mask(word, begin, end);
+    }
+}
+
+/// Implements `Shl<usize>`, `Shr<usize>` and their assigning variants for an oversized bitmap.
+/// Shifting by the bitmap's length or more clears every bit, rather than panicking as the
+/// integer types do.
+macro_rules! impl_shifts {
+    ($bitmap:ident) => {
+        impl core::ops::Shl<usize> for $bitmap {
+            type Output = Self;
+
+            fn shl(mut self, rhs: usize) -> Self::Output {
+                self <<= rhs;
+                self
+            }
+        }
+
+        impl core::ops::ShlAssign<usize> for $bitmap {
+            fn shl_assign(&mut self, rhs: usize) {
+                super::shift_elements_left(&mut self.0, rhs.min($bitmap::MAP_LENGTH));
+            }
+        }
+
+        impl core::ops::Shr<usize> for $bitmap {
+            type Output = Self;
+
+            fn shr(mut self, rhs: usize) -> Self::Output {
+                self >>= rhs;
+                self
+            }
+        }
+
+        impl core::ops::ShrAssign<usize> for $bitmap {
+            fn shr_assign(&mut self, rhs: usize) {
+                super::shift_elements_right(&mut self.0, rhs.min($bitmap::MAP_LENGTH));
+            }
+        }
+    };
+}
+
 mod bitmap_1024;
 mod bitmap_2048;
 mod bitmap_256;
@@ -5,9 +136,9 @@ mod bitmap_4096;
 mod bitmap_512;
 mod bitmap_kb;
 
-pub use bitmap_1024::Bitmap1024;
-pub use bitmap_2048::Bitmap2048;
-pub use bitmap_256::Bitmap256;
-pub use bitmap_4096::Bitmap4096;
-pub use bitmap_512::Bitmap512;
-pub use bitmap_kb::BitmapKB;
+pub use bitmap_1024::{Bitmap1024, Bitmap1024Bits, Bitmap1024SetBits};
+pub use bitmap_2048::{Bitmap2048, Bitmap2048Bits, Bitmap2048SetBits};
+pub use bitmap_256::{Bitmap256, Bitmap256Bits, Bitmap256SetBits};
+pub use bitmap_4096::{Bitmap4096, Bitmap4096Bits, Bitmap4096SetBits};
+pub use bitmap_512::{Bitmap512, Bitmap512Bits, Bitmap512SetBits};
+pub use bitmap_kb::{BitmapKB, BitmapKBBits, BitmapKBSetBits};
@@ -1,14 +1,19 @@
-use super::BitmapSize;
+use super::{BitmapOps, BitmapSize};
+use crate::{BitmapError, ParseBitmapError};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
 use core::fmt::Formatter;
-use serde::{Deserialize, Serialize};
-use std::{
-    fmt::{Debug, Display},
+use core::{
+    fmt::{Binary, Debug, Display},
+    iter::FromIterator,
     mem,
     ops::{
         Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, Div,
-        DivAssign, Mul, MulAssign, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+        DivAssign, Index, Mul, MulAssign, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
     },
+    str::FromStr,
 };
+use serde::{Deserialize, Serialize};
 
 /// A bitmap of length 64.
 ///
@@ -53,199 +58,1668 @@ impl Bitmap64 {
         self.0
     }
 
-    /// Creates a new bitmap with all bits set to the given value.
+    /// Returns a reference to the underlying integer.
+    pub fn inner(&self) -> &u64 {
+        &self.0
+    }
+
+    /// Consumes the bitmap, returning the underlying integer.
+    pub fn into_inner(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the bitmap's value as a byte array in big-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bytes = Bitmap64::from(1).to_be_bytes();
+    /// assert_eq!(bytes[bytes.len() - 1], 1);
+    /// assert!(bytes[..bytes.len() - 1].iter().all(|&byte| byte == 0));
+    /// ```
+    pub fn to_be_bytes(&self) -> [u8; mem::size_of::<u64>()] {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates a bitmap from its value as a byte array in big-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1011);
+    /// assert_eq!(Bitmap64::from_be_bytes(bitmap.to_be_bytes()), bitmap);
+    /// ```
+    pub fn from_be_bytes(bytes: [u8; mem::size_of::<u64>()]) -> Bitmap64 {
+        Bitmap64(u64::from_be_bytes(bytes))
+    }
+
+    /// Returns the bitmap's value as a byte array in little-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bytes = Bitmap64::from(1).to_le_bytes();
+    /// assert_eq!(bytes[0], 1);
+    /// assert!(bytes[1..].iter().all(|&byte| byte == 0));
+    /// ```
+    pub fn to_le_bytes(&self) -> [u8; mem::size_of::<u64>()] {
+        self.0.to_le_bytes()
+    }
+
+    /// Creates a bitmap from its value as a byte array in little-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1011);
+    /// assert_eq!(Bitmap64::from_le_bytes(bitmap.to_le_bytes()), bitmap);
+    /// ```
+    pub fn from_le_bytes(bytes: [u8; mem::size_of::<u64>()]) -> Bitmap64 {
+        Bitmap64(u64::from_le_bytes(bytes))
+    }
+
+    /// Parses a hexadecimal string, with an optional `0x` prefix and either case of `a` to `f`.
+    /// Strings with fewer digits than the bitmap can hold are padded with leading zeros.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(*Bitmap64::from_hex_str("0xA5").unwrap(), 0xA5);
+    /// assert_eq!(*Bitmap64::from_hex_str("1f").unwrap(), 0x1F);
+    /// assert!(Bitmap64::from_hex_str("0xG").is_err());
+    /// ```
+    pub fn from_hex_str(s: &str) -> Result<Bitmap64, ParseBitmapError> {
+        let (prefix_length, digits) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(digits) => (2, digits),
+            None => (0, s),
+        };
+
+        if digits.is_empty() {
+            return Err(ParseBitmapError::Empty);
+        }
+
+        if digits.len() > Bitmap64::MAP_LENGTH / 4 {
+            return Err(ParseBitmapError::TooLong {
+                length: digits.len(),
+                capacity: Bitmap64::MAP_LENGTH / 4,
+            });
+        }
+
+        if let Some((index, character)) = digits
+            .char_indices()
+            .find(|(_, character)| !character.is_ascii_hexdigit())
+        {
+            return Err(ParseBitmapError::InvalidDigit {
+                character,
+                index: prefix_length + index,
+            });
+        }
+
+        // Every digit is valid and there are few enough of them to fit, so this can't fail
+        Ok(Bitmap64(u64::from_str_radix(digits, 16).unwrap()))
+    }
+
+    /// Creates a new bitmap with all bits set to the given value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::new(true);
+    /// assert_eq!(*a, u64::MAX);
+    ///
+    /// let b = Bitmap64::new(false);
+    /// assert_eq!(*b, 0);
+    /// ```
+    pub fn new(value: bool) -> Bitmap64 {
+        Bitmap64(if value { u64::MAX } else { 0 })
+    }
+
+    /// Create a new bitmap that has its bits set from `begin` (inclusive) to `end` (exclusive).
+    /// If begin is greater than the map length or end is 0, will return a bitmap with all bits set to
+    /// the opposite of value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::create_bit_mask(3, 7, true);
+    /// assert_eq!(*a, 0b1111000);
+    ///
+    /// let b = Bitmap64::create_bit_mask(3, 6, false); // Results in 1..1000111
+    /// assert_eq!(b, Bitmap64::new(true) ^ 0b111000);
+    /// ```
+    pub fn create_bit_mask(begin: usize, end: usize, value: bool) -> Bitmap64 {
+        if value {
+            if begin >= Bitmap64::MAP_LENGTH || end < 1 {
+                Bitmap64(0)
+            } else if end >= Bitmap64::MAP_LENGTH {
+                Bitmap64(u64::MAX << begin)
+            } else {
+                Bitmap64((u64::MAX << begin) & (u64::MAX >> (Bitmap64::MAP_LENGTH - end)))
+            }
+        } else {
+            !Bitmap64::create_bit_mask(begin, end, true)
+        }
+    }
+
+    /// Creates a new bitmap with the lowest `n` bits set, and all others cleared. This is
+    /// equivalent to `Bitmap64::create_bit_mask(0, n, true)`. If `n` is at least the map
+    /// length, all bits will be set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::spread(5);
+    /// assert_eq!(*a, 0b11111);
+    ///
+    /// let b = Bitmap64::spread(Bitmap64::capacity());
+    /// assert_eq!(*b, u64::MAX);
+    /// ```
+    pub fn spread(n: usize) -> Bitmap64 {
+        Bitmap64::create_bit_mask(0, n, true)
+    }
+
+    /// Creates a new bitmap with alternating bits, `...0101` if `start_with_one` is true
+    /// (the bit at index 0 is set), or `...1010` otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::from_alternating(true);
+    /// assert_eq!(*a & 0b1111, 0b0101);
+    ///
+    /// let b = Bitmap64::from_alternating(false);
+    /// assert_eq!(*b & 0b1111, 0b1010);
+    /// ```
+    pub fn from_alternating(start_with_one: bool) -> Bitmap64 {
+        // Dividing all 1's by 0b11 leaves 0b0101...0101
+        let pattern = u64::MAX / 3;
+
+        if start_with_one {
+            Bitmap64(pattern)
+        } else {
+            Bitmap64(!pattern)
+        }
+    }
+
+    /// Creates a new bitmap with every byte set to the given byte.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from_repeating_byte(0b10100101);
+    /// assert_eq!(*bitmap & 0xFF, 0b10100101);
+    /// assert_eq!(Bitmap64::from_repeating_byte(0xFF), Bitmap64::new(true));
+    /// ```
+    pub fn from_repeating_byte(byte: u8) -> Bitmap64 {
+        Bitmap64(u64::from_ne_bytes([byte; mem::size_of::<u64>()]))
+    }
+
+    /// Creates a new bitmap taking the bits of `value` only where `mask` is set, and 0
+    /// everywhere else. This is the same as `mask & value`, but names the intent of
+    /// selecting bits from a value, as is common when accessing hardware registers.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mask = Bitmap64::from(0b1100);
+    /// let value = Bitmap64::from(0b1010);
+    /// assert_eq!(*Bitmap64::from_mask_and_value(mask, value), 0b1000);
+    /// ```
+    pub fn from_mask_and_value(mask: Bitmap64, value: Bitmap64) -> Bitmap64 {
+        mask & value
+    }
+
+    /// Creates a new, empty `Bitmap64`, and sets the desired index before returning.
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::from_set(5).unwrap();
+    ///
+    /// // The above is equivalent to:
+    ///
+    /// let mut b = Bitmap64::from(0);
+    /// b.set(5, true);
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn from_set(index: usize) -> Result<Bitmap64, BitmapError> {
+        let mut bitmap = Bitmap64::default();
+        bitmap.set(index, true)?;
+        Ok(bitmap)
+    }
+
+    /// Creates a new bitmap with each of the given indices set.
+    ///
+    /// **The indices must be sorted in ascending order.** Since only the last index can be
+    /// the largest, it's the only one that needs to be checked against the bounds of the
+    /// bitmap, and the rest can be set in a single linear scan. Repeated indices are fine.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if the indices aren't sorted in ascending order, or if
+    /// any of them is out of bounds. The ordering is checked in every build rather than with a
+    /// debug-mode assertion, so unsorted input gives `BitmapError::UnsortedIndices` instead of
+    /// a panic.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::from_sorted_indices(&[0, 3, 5]).unwrap();
+    /// assert_eq!(*a, 0b101001);
+    ///
+    /// assert!(Bitmap64::from_sorted_indices(&[3, 0, 5]).is_err());
+    /// ```
+    pub fn from_sorted_indices(indices: &[usize]) -> Result<Bitmap64, BitmapError> {
+        let last = match indices.last() {
+            Some(&last) => last,
+            None => return Ok(Bitmap64::default()),
+        };
+
+        if last >= Bitmap64::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index: last,
+                capacity: Bitmap64::MAP_LENGTH,
+            });
+        }
+
+        let mut bitmap = Bitmap64::default();
+        let mut previous = 0;
+
+        for &index in indices {
+            // Any index larger than the last one also means the indices aren't sorted
+            if index < previous || index > last {
+                return Err(BitmapError::UnsortedIndices { index, previous });
+            }
+
+            bitmap.0 |= 1 << index;
+            previous = index;
+        }
+
+        Ok(bitmap)
+    }
+
+    /// Creates a new bitmap with each of the given indices set, silently ignoring any that
+    /// are out of bounds. See `from_indices_checked()` for a version that doesn't.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::from_indices(vec![5, 0, 3]);
+    /// assert_eq!(*a, 0b101001);
+    ///
+    /// // Round trips with set_bits()
+    /// assert_eq!(Bitmap64::from_indices(a.set_bits()), a);
+    /// ```
+    pub fn from_indices(indices: impl IntoIterator<Item = usize>) -> Bitmap64 {
+        let mut bitmap = Bitmap64::default();
+
+        for index in indices {
+            // Out-of-bounds indices are meant to be ignored here
+            let _ = bitmap.set(index, true);
+        }
+
+        bitmap
+    }
+
+    /// Creates a new bitmap with each of the given indices set.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` as soon as an out-of-bounds index is found.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::from_indices_checked(vec![5, 0, 3]).unwrap();
+    /// assert_eq!(*a, 0b101001);
+    ///
+    /// assert!(Bitmap64::from_indices_checked(vec![0, Bitmap64::capacity()]).is_err());
+    /// ```
+    pub fn from_indices_checked(
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Result<Bitmap64, BitmapError> {
+        let mut bitmap = Bitmap64::default();
+
+        for index in indices {
+            bitmap.set(index, true)?;
+        }
+
+        Ok(bitmap)
+    }
+
+    /// Sets the desired index, to the value provided. Note that indexing starts
+    /// at 0.
+    ///
+    /// ## Returns
+    ///
+    /// Returns a `Result` based on the outcome. If an `Err<BitmapError>` was returned,
+    /// it was because an out-of-bounds index was attempted to be set. In that
+    /// case the bitmap's state remains unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mut bitmap = Bitmap64::default();
+    /// assert_eq!(*bitmap, 0);
+    ///
+    /// bitmap.set(4, true);
+    /// assert_eq!(*bitmap, 16);
+    /// ```
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), BitmapError> {
+        if index >= Bitmap64::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap64::MAP_LENGTH,
+            });
+        }
+
+        if value {
+            let mask = 1 << index;
+            self.0 |= mask;
+        } else {
+            let mask = u64::MAX - (1 << index);
+            self.0 &= mask;
+        }
+
+        Ok(())
+    }
+
+    /// Set bits from begin (inclusive) to end (exclusive) to the given value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mut bitmap = Bitmap64::default();
+    /// assert_eq!(*bitmap, 0);
+    ///
+    /// bitmap.set_range(2, 7, true);
+    /// assert_eq!(*bitmap, 0b1111100);
+    ///
+    /// bitmap.set_range(3, 5, false);
+    /// assert_eq!(*bitmap, 0b1100100);
+    /// ```
+    pub fn set_range(&mut self, begin: usize, end: usize, value: bool) {
+        if value {
+            *self |= Bitmap64::create_bit_mask(begin, end, true);
+        } else {
+            *self &= Bitmap64::create_bit_mask(begin, end, false);
+        }
+    }
+
+    /// Flips the bit at the given index. Note that indexing starts at 0.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if the index is out of bounds, in which case the
+    /// bitmap's state remains unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mut bitmap = Bitmap64::from(0b1010);
+    ///
+    /// bitmap.toggle(1).unwrap();
+    /// assert_eq!(*bitmap, 0b1000);
+    ///
+    /// bitmap.toggle(0).unwrap();
+    /// assert_eq!(*bitmap, 0b1001);
+    /// ```
+    pub fn toggle(&mut self, index: usize) -> Result<(), BitmapError> {
+        if index >= Bitmap64::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap64::MAP_LENGTH,
+            });
+        }
+
+        self.0 ^= 1 << index;
+        Ok(())
+    }
+
+    /// Flips the bits from begin (inclusive) to end (exclusive).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mut bitmap = Bitmap64::from(0b1100);
+    ///
+    /// bitmap.toggle_range(1, 4);
+    /// assert_eq!(*bitmap, 0b0010);
+    /// ```
+    pub fn toggle_range(&mut self, begin: usize, end: usize) {
+        *self ^= Bitmap64::create_bit_mask(begin, end, true);
+    }
+
+    /// Swaps the bits at indices `i` and `j`. Nothing is written if both bits are equal.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if either index is out of bounds, in which case the
+    /// bitmap's state remains unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mut bitmap = Bitmap64::from(0b0001);
+    ///
+    /// bitmap.swap_bits(0, 3).unwrap();
+    /// assert_eq!(*bitmap, 0b1000);
+    /// ```
+    pub fn swap_bits(&mut self, i: usize, j: usize) -> Result<(), BitmapError> {
+        if self.get(i)? != self.get(j)? {
+            self.0 ^= (1 << i) | (1 << j);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the bit at the given index. Note that indexing starts at 0.
+    ///
+    /// ## Returns
+    ///
+    /// Returns a `Result` based on the outcome.
+    ///
+    /// If `Ok<bool>` is returned, then the contained value in ok is the state
+    /// of the given bit
+    ///
+    /// If an `Err<BitmapError>` was returned, it was because you tried to get
+    /// an out-of-bounds index.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1010);
+    /// assert_eq!(bitmap.get(2).unwrap(), false);
+    /// assert_eq!(bitmap.get(3).unwrap(), true);
+    /// ```
+    pub fn get(&self, index: usize) -> Result<bool, BitmapError> {
+        if index >= Bitmap64::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: Bitmap64::MAP_LENGTH,
+            });
+        }
+
+        let mask = 1 << index;
+        Ok(self.0 & mask > 0)
+    }
+
+    /// Gets the bits from begin (inclusive) to end (exclusive) packed into the low bits of a
+    /// `u64`, with bit 0 of the result being the bit at `begin`. Useful for reading integer
+    /// fields out of a packed bitmap.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if `begin > end`, if `end` is beyond the bitmap's length,
+    /// or if the range is wider than 64 bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1011_0110);
+    /// assert_eq!(bitmap.get_range(1, 4).unwrap(), 0b011);
+    /// assert_eq!(bitmap.get_range(4, 8).unwrap(), 0b1011);
+    /// ```
+    #[allow(clippy::unnecessary_cast)]
+    pub fn get_range(&self, begin: usize, end: usize) -> Result<u64, BitmapError> {
+        if begin > end || end > Bitmap64::MAP_LENGTH {
+            return Err(BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity: Bitmap64::MAP_LENGTH,
+            });
+        }
+
+        let width = end - begin;
+        if width > 64 {
+            return Err(BitmapError::RangeTooWide {
+                width,
+                max_width: 64,
+            });
+        }
+
+        if width == 0 {
+            return Ok(0);
+        }
+
+        Ok((self.0 >> begin) as u64 & (u64::MAX >> (64 - width)))
+    }
+
+    /// Writes the low `end - begin` bits of `value` into the bitmap from begin (inclusive) to
+    /// end (exclusive), with bit 0 of `value` going to `begin`. Any bits of `value` above the
+    /// width of the range are ignored. This is the inverse of `get_range()`.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if `begin > end`, if `end` is beyond the bitmap's length,
+    /// or if the range is wider than 64 bits, in which case the bitmap's state remains
+    /// unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mut bitmap = Bitmap64::from(0b1000_0001);
+    /// bitmap.set_bits_from_value(1, 5, 0b1_0110).unwrap();
+    /// assert_eq!(*bitmap, 0b1000_1101);
+    /// assert_eq!(bitmap.get_range(1, 5).unwrap(), 0b0110);
+    /// ```
+    #[allow(clippy::unnecessary_cast)]
+    pub fn set_bits_from_value(
+        &mut self,
+        begin: usize,
+        end: usize,
+        value: u64,
+    ) -> Result<(), BitmapError> {
+        if begin > end || end > Bitmap64::MAP_LENGTH {
+            return Err(BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity: Bitmap64::MAP_LENGTH,
+            });
+        }
+
+        let width = end - begin;
+        if width > 64 {
+            return Err(BitmapError::RangeTooWide {
+                width,
+                max_width: 64,
+            });
+        }
+
+        if width == 0 {
+            return Ok(());
+        }
+
+        let field = value & (u64::MAX >> (64 - width));
+        self.set_range(begin, end, false);
+        self.0 |= (field as u64) << begin;
+        Ok(())
+    }
+
+    /// Gets the bit at the given index without checking that the index is in bounds.
+    ///
+    /// ## Safety
+    ///
+    /// Calling this with `index >= Bitmap64::capacity()` is undefined behaviour. Use `get()`
+    /// unless the index has already been checked and the bounds check is measurably slow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1010);
+    /// assert!(unsafe { bitmap.get_unchecked(3) });
+    /// assert!(!unsafe { bitmap.get_unchecked(2) });
+    /// ```
+    pub unsafe fn get_unchecked(&self, index: usize) -> bool {
+        debug_assert!(index < Bitmap64::MAP_LENGTH);
+        self.0 & (1 << index) > 0
+    }
+
+    /// Sets the bit at the given index to the value provided without checking that the index
+    /// is in bounds.
+    ///
+    /// ## Safety
+    ///
+    /// Calling this with `index >= Bitmap64::capacity()` is undefined behaviour. Use `set()`
+    /// unless the index has already been checked and the bounds check is measurably slow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let mut bitmap = Bitmap64::default();
+    /// unsafe { bitmap.set_unchecked(4, true) };
+    /// assert_eq!(*bitmap, 0b10000);
+    /// ```
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: bool) {
+        debug_assert!(index < Bitmap64::MAP_LENGTH);
+        if value {
+            self.0 |= 1 << index;
+        } else {
+            self.0 &= !(1 << index);
+        }
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the left, wrapping the truncated bits
+    /// around to the low end. As with the integer types, `n` is taken modulo the map length.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b101);
+    /// assert_eq!(*bitmap.rotate_left(2), 0b10100);
+    /// assert_eq!(bitmap.rotate_left(Bitmap64::capacity() as u32), bitmap);
+    /// ```
+    pub const fn rotate_left(&self, n: u32) -> Bitmap64 {
+        Bitmap64(self.0.rotate_left(n))
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the right, wrapping the truncated bits
+    /// around to the high end. As with the integer types, `n` is taken modulo the map length.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b10100);
+    /// assert_eq!(*bitmap.rotate_right(2), 0b101);
+    /// assert_eq!(bitmap.rotate_right(Bitmap64::capacity() as u32), bitmap);
+    /// ```
+    pub const fn rotate_right(&self, n: u32) -> Bitmap64 {
+        Bitmap64(self.0.rotate_right(n))
+    }
+
+    /// Reverses the order of the bits, so the bit at index 0 ends up at the highest index and
+    /// vice versa.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1);
+    /// assert_eq!(bitmap.reverse_bits(), Bitmap64::from_set(Bitmap64::capacity() - 1).unwrap());
+    /// ```
+    pub const fn reverse_bits(&self) -> Bitmap64 {
+        Bitmap64(self.0.reverse_bits())
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the left, wrapping the truncated bits
+    /// around to the low end. Returns `None` if `n` isn't less than the map length, as
+    /// rotating by that much is more likely a bug than intended.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(1);
+    /// assert_eq!(bitmap.checked_rotate_left(3), Some(Bitmap64::from(0b1000)));
+    /// assert_eq!(bitmap.checked_rotate_left(Bitmap64::capacity()), None);
+    /// ```
+    pub fn checked_rotate_left(&self, n: usize) -> Option<Bitmap64> {
+        if n >= Bitmap64::MAP_LENGTH {
+            return None;
+        }
+
+        Some(self.rotate_left(n as u32))
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the right, wrapping the truncated bits
+    /// around to the high end. Returns `None` if `n` isn't less than the map length, as
+    /// rotating by that much is more likely a bug than intended.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1000);
+    /// assert_eq!(bitmap.checked_rotate_right(3), Some(Bitmap64::from(1)));
+    /// assert_eq!(bitmap.checked_rotate_right(Bitmap64::capacity()), None);
+    /// ```
+    pub fn checked_rotate_right(&self, n: usize) -> Option<Bitmap64> {
+        if n >= Bitmap64::MAP_LENGTH {
+            return None;
+        }
+
+        Some(self.rotate_right(n as u32))
+    }
+
+    /// Shifts the bitmap `n` places to the left, filling the vacated low bits with `fill`
+    /// rather than always with 0's. Shifting by the map length or more shifts out every bit.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b101);
+    /// assert_eq!(*bitmap.shift_left_or(4, true), 0b1011111);
+    /// assert_eq!(*bitmap.shift_left_or(4, false), 0b1010000);
+    /// ```
+    pub fn shift_left_or(&self, n: usize, fill: bool) -> Bitmap64 {
+        let shifted = if n >= Bitmap64::MAP_LENGTH {
+            Bitmap64(0)
+        } else {
+            *self << n
+        };
+
+        if fill {
+            shifted | Bitmap64::spread(n)
+        } else {
+            shifted
+        }
+    }
+
+    /// Shifts the bitmap `n` places to the right, filling the vacated high bits with `fill`
+    /// rather than always with 0's. Shifting by the map length or more shifts out every bit.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1010000);
+    /// assert_eq!(*bitmap.shift_right_or(4, false), 0b101);
+    /// assert_eq!(bitmap.shift_right_or(4, true), Bitmap64::from(0b101) | !(Bitmap64::new(true) >> 4));
+    /// ```
+    pub fn shift_right_or(&self, n: usize, fill: bool) -> Bitmap64 {
+        let shifted = if n >= Bitmap64::MAP_LENGTH {
+            Bitmap64(0)
+        } else {
+            *self >> n
+        };
+
+        if fill {
+            shifted | !Bitmap64::spread(Bitmap64::MAP_LENGTH.saturating_sub(n))
+        } else {
+            shifted
+        }
+    }
+
+    /// Selects the bits of this bitmap at the positions set in `mask`, and packs them
+    /// contiguously into the low bits of the returned bitmap, preserving their order. This is
+    /// the parallel bit extract operation, the inverse of `pdep()`.
+    ///
+    /// Uses the BMI2 `PEXT` instruction when compiled for a target that supports it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b10110100);
+    ///
+    /// // Selects the bits at the even indices, 0, 1, 1 and 0 from lowest to highest
+    /// let packed = bitmap.pext(Bitmap64::from(0b01010101));
+    /// assert_eq!(packed, Bitmap64::from(0b0110));
+    /// ```
+    pub fn pext(&self, mask: Bitmap64) -> Bitmap64 {
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[allow(clippy::unnecessary_cast)]
+        {
+            if mem::size_of::<u64>() <= mem::size_of::<u64>() {
+                // Safe as the bmi2 target feature is guaranteed to be enabled here
+                let value = unsafe { core::arch::x86_64::_pext_u64(self.0 as u64, mask.0 as u64) };
+                return Bitmap64(value as u64);
+            }
+        }
+
+        let mut value = 0;
+        let mut remaining = mask.0;
+        let mut position = 0;
+
+        while remaining != 0 {
+            let index = remaining.trailing_zeros();
+            if (self.0 >> index) & 1 == 1 {
+                value |= 1 << position;
+            }
+            remaining &= remaining - 1;
+            position += 1;
+        }
+
+        Bitmap64(value)
+    }
+
+    /// Scatters the low bits of `src` into the positions set in `mask`, in order, leaving every
+    /// other bit cleared. This is the parallel bit deposit operation, the inverse of `pext()`.
+    ///
+    /// Uses the BMI2 `PDEP` instruction when compiled for a target that supports it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// // Deposits 0, 1, 1 and 0 from lowest to highest into the even indices
+    /// let bitmap = Bitmap64::pdep(Bitmap64::from(0b01010101), Bitmap64::from(0b0110));
+    /// assert_eq!(bitmap, Bitmap64::from(0b00010100));
+    /// ```
+    pub fn pdep(mask: Bitmap64, src: Bitmap64) -> Bitmap64 {
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[allow(clippy::unnecessary_cast)]
+        {
+            if mem::size_of::<u64>() <= mem::size_of::<u64>() {
+                // Safe as the bmi2 target feature is guaranteed to be enabled here
+                let value = unsafe { core::arch::x86_64::_pdep_u64(src.0 as u64, mask.0 as u64) };
+                return Bitmap64(value as u64);
+            }
+        }
+
+        let mut value = 0;
+        let mut remaining = mask.0;
+        let mut position = 0;
+
+        while remaining != 0 {
+            let index = remaining.trailing_zeros();
+            if (src.0 >> position) & 1 == 1 {
+                value |= 1 << index;
+            }
+            remaining &= remaining - 1;
+            position += 1;
+        }
+
+        Bitmap64(value)
+    }
+
+    /// Selects the bits of this bitmap at the positions set in `mask`, and packs them
+    /// contiguously into the low bits of the returned value, preserving their order.
+    /// The result is returned as a raw `u64` rather than a bitmap, as it represents a
+    /// value rather than a set of bits. See `pext()` for a version returning a bitmap.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b10110100);
+    ///
+    /// // Selects the bits at the even indices, 0, 1, 1 and 0 from lowest to highest
+    /// let value = bitmap.pext_select(Bitmap64::from(0b01010101));
+    /// assert_eq!(value, 0b0110);
+    /// ```
+    pub fn pext_select(&self, mask: Bitmap64) -> u64 {
+        self.pext(mask).0
+    }
+
+    /// Returns the next larger bitmap with the same number of set bits, or `None` if there is
+    /// none, which is the case when every set bit is already at the high end, or no bits are
+    /// set. Starting from the lowest `k` bits set, repeatedly calling this enumerates every
+    /// bitmap with `k` set bits in ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b0111);
+    /// assert_eq!(bitmap.next_permutation(), Some(Bitmap64::from(0b1011)));
+    /// assert_eq!(
+    ///     Bitmap64::from(0b1011).next_permutation(),
+    ///     Some(Bitmap64::from(0b1101))
+    /// );
+    /// assert_eq!(Bitmap64::new(true).next_permutation(), None);
+    /// ```
+    pub fn next_permutation(&self) -> Option<Bitmap64> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        // Gosper's hack: the lowest block of set bits has its top bit moved up by one, and
+        // the rest of the block is moved down to the lowest positions
+        let lowest = self.0 & self.0.wrapping_neg();
+        let ripple = self.0.checked_add(lowest)?;
+        let ones = ((ripple ^ self.0) >> 2) / lowest;
+        Some(Bitmap64(ripple | ones))
+    }
+
+    /// Returns the next smaller bitmap with the same number of set bits, or `None` if there
+    /// is none, which is the case when every set bit is already at the low end. This is the
+    /// inverse of `next_permutation()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1101);
+    /// assert_eq!(bitmap.prev_permutation(), Some(Bitmap64::from(0b1011)));
+    /// assert_eq!(Bitmap64::from(0b0111).prev_permutation(), None);
+    /// ```
+    pub fn prev_permutation(&self) -> Option<Bitmap64> {
+        // Inverting reverses the order of the bitmaps, so the previous bitmap is the inverse of
+        // the next bitmap after the inverse
+        Bitmap64(!self.0)
+            .next_permutation()
+            .map(|next| Bitmap64(!next.0))
+    }
+
+    /// Counts the number of runs of consecutive bits with the same value. This is always
+    /// one more than the number of times neighbouring bits change value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// // One run of 0's at the top, then a run of 1's, then another run of 0's
+    /// let bitmap = Bitmap64::from(0b00111100);
+    /// assert_eq!(bitmap.count_runs(), 3);
+    ///
+    /// assert_eq!(Bitmap64::default().count_runs(), 1);
+    /// ```
+    pub fn count_runs(&self) -> usize {
+        // Each set bit marks a change between a bit and the next one up, ignoring the top bit
+        // as it has nothing above it to compare against
+        let transitions = (self.0 ^ (self.0 >> 1)) & (u64::MAX >> 1);
+        transitions.count_ones() as usize + 1
+    }
+
+    /// Counts the number of bits that are set. This returns a `u32` like the integer types do;
+    /// `BitmapOps::count_ones()` gives the same count as a `usize`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b1011);
+    /// assert_eq!(bitmap.count_ones(), 3);
+    /// ```
+    pub fn count_ones(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns the parity of the bitmap, which is the XOR of all of its bits. In other words,
+    /// true if an odd number of bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert!(Bitmap64::from(0b1011).parity());
+    /// assert!(!Bitmap64::from(0b1001).parity());
+    /// ```
+    pub fn parity(&self) -> bool {
+        self.0.count_ones() & 1 == 1
+    }
+
+    /// Counts the number of positions at which the bits of the two bitmaps differ.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::from(0b1011);
+    /// let b = Bitmap64::from(0b0110);
+    /// assert_eq!(a.hamming_distance(&b), 3);
+    /// ```
+    pub fn hamming_distance(&self, other: &Bitmap64) -> usize {
+        (self.0 ^ other.0).count_ones() as usize
+    }
+
+    /// Returns true if every bit set in this bitmap is also set in `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let required = Bitmap64::from(0b0101);
+    /// let granted = Bitmap64::from(0b1101);
+    /// assert!(required.is_subset(&granted));
+    /// assert!(!granted.is_subset(&required));
+    /// ```
+    pub fn is_subset(&self, other: &Bitmap64) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    /// Returns true if every bit set in `other` is also set in this bitmap.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let granted = Bitmap64::from(0b1101);
+    /// assert!(granted.is_superset(&Bitmap64::from(0b0101)));
+    /// assert!(!granted.is_superset(&Bitmap64::from(0b0010)));
+    /// ```
+    pub fn is_superset(&self, other: &Bitmap64) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns true if the two bitmaps have no set bits in common.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let held = Bitmap64::from(0b0011);
+    /// assert!(held.is_disjoint(&Bitmap64::from(0b1100)));
+    /// assert!(!held.is_disjoint(&Bitmap64::from(0b0110)));
+    /// ```
+    pub fn is_disjoint(&self, other: &Bitmap64) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// Returns a bitmap of the bits set in this bitmap but not in `other`. This is the same as
+    /// `bitmap & !other`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let a = Bitmap64::from(0b1101);
+    /// let b = Bitmap64::from(0b0110);
+    /// assert_eq!(*a.difference(&b), 0b1001);
+    /// ```
+    pub fn difference(&self, other: &Bitmap64) -> Bitmap64 {
+        Bitmap64(self.0 & !other.0)
+    }
+
+    /// Returns an iterator over the indices of all set bits, in ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b101001);
+    /// let indices: Vec<usize> = bitmap.set_bits().collect();
+    /// assert_eq!(indices, vec![0, 3, 5]);
+    /// ```
+    pub fn set_bits(&self) -> Bitmap64SetBits {
+        Bitmap64SetBits(self.0)
+    }
+
+    /// Returns an iterator over the indices of all cleared bits, in ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = !Bitmap64::from(0b101001);
+    /// let indices: Vec<usize> = bitmap.clear_bits().collect();
+    /// assert_eq!(indices, vec![0, 3, 5]);
+    /// ```
+    pub fn clear_bits(&self) -> Bitmap64ClearBits {
+        Bitmap64ClearBits(Bitmap64SetBits(!self.0))
+    }
+
+    /// Returns an iterator over every bit as a `bool`, starting from index 0.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b101);
+    /// let bits: Vec<bool> = bitmap.bits().take(4).collect();
+    /// assert_eq!(bits, vec![true, false, true, false]);
+    /// ```
+    pub fn bits(&self) -> Bitmap64Bits {
+        Bitmap64Bits {
+            bitmap: self.0,
+            index: 0,
+        }
+    }
+
+    /// Returns true if no bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert!(Bitmap64::default().is_empty());
+    /// assert!(!Bitmap64::from(0b100).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if any bit is set. This is the opposite of `is_empty()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert!(Bitmap64::from(0b100).any());
+    /// assert!(!Bitmap64::default().any());
+    /// ```
+    pub fn any(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Returns true if every bit is set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert!(Bitmap64::new(true).is_full());
+    /// assert!(!Bitmap64::from(0b100).is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.0 == u64::MAX
+    }
+
+    /// Finds the index of the lowest set bit, or `None` if no bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(Bitmap64::from(0b10100).find_first_set(), Some(2));
+    /// assert_eq!(Bitmap64::default().find_first_set(), None);
+    /// ```
+    pub fn find_first_set(&self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.0.trailing_zeros() as usize)
+        }
+    }
+
+    /// Finds the index of the highest set bit, or `None` if no bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(Bitmap64::from(0b10100).find_last_set(), Some(4));
+    /// assert_eq!(Bitmap64::default().find_last_set(), None);
+    /// ```
+    pub fn find_last_set(&self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(Bitmap64::MAP_LENGTH - 1 - self.0.leading_zeros() as usize)
+        }
+    }
+
+    /// Finds the index of the lowest cleared bit, or `None` if every bit is set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(Bitmap64::from(0b10111).find_first_clear(), Some(3));
+    /// assert_eq!(Bitmap64::new(true).find_first_clear(), None);
+    /// ```
+    pub fn find_first_clear(&self) -> Option<usize> {
+        (!*self).find_first_set()
+    }
+
+    /// Finds the index of the highest cleared bit, or `None` if every bit is set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::new(true) >> 1;
+    /// assert_eq!(bitmap.find_last_clear(), Some(Bitmap64::capacity() - 1));
+    /// assert_eq!(Bitmap64::new(true).find_last_clear(), None);
+    /// ```
+    pub fn find_last_clear(&self) -> Option<usize> {
+        (!*self).find_last_set()
+    }
+
+    /// Finds the index of the lowest set bit strictly after the index `after`, or `None` if
+    /// there are no set bits beyond that point. Out-of-bounds values of `after` return `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let bitmap = Bitmap64::from(0b100101);
+    /// assert_eq!(bitmap.find_next_set(0), Some(2));
+    /// assert_eq!(bitmap.find_next_set(2), Some(5));
+    /// assert_eq!(bitmap.find_next_set(5), None);
+    /// ```
+    pub fn find_next_set(&self, after: usize) -> Option<usize> {
+        if after >= Bitmap64::MAP_LENGTH - 1 {
+            return None;
+        }
+
+        Bitmap64(self.0 & (u64::MAX << (after + 1))).find_first_set()
+    }
+
+    /// Returns the full-width binary representation of the bitmap, as shown by `Display`, with
+    /// an underscore between every `group_size` bits counting from the most significant bit. A
+    /// `group_size` of 0 leaves the string ungrouped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let grouped = Bitmap64::from(0xA5).to_binary_string_grouped(4);
+    /// assert!(grouped.ends_with("1010_0101"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_binary_string_grouped(&self, group_size: usize) -> String {
+        let binary = self.to_string();
+        if group_size == 0 {
+            return binary;
+        }
+
+        let mut grouped = String::with_capacity(binary.len() + binary.len() / group_size);
+        for (i, digit) in binary.chars().enumerate() {
+            if i > 0 && i % group_size == 0 {
+                grouped.push('_');
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Adds `rhs` to the bitmap's value, wrapping around at the boundary of the type rather
+    /// than panicking on overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(*Bitmap64::new(true).wrapping_add(2), 1);
+    /// ```
+    pub fn wrapping_add(self, rhs: u64) -> Bitmap64 {
+        Bitmap64(self.0.wrapping_add(rhs))
+    }
+
+    /// Subtracts `rhs` from the bitmap's value, wrapping around at the boundary of the type
+    /// rather than panicking on underflow.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::Bitmap64;
     ///
-    /// let a = Bitmap64::new(true);
-    /// assert_eq!(*a, u64::MAX);
-    ///
-    /// let b = Bitmap64::new(false);
-    /// assert_eq!(*b, 0);
+    /// assert_eq!(Bitmap64::from(1).wrapping_sub(2), Bitmap64::new(true));
     /// ```
-    pub fn new(value: bool) -> Bitmap64 {
-        Bitmap64(if value { u64::MAX } else { 0 })
+    pub fn wrapping_sub(self, rhs: u64) -> Bitmap64 {
+        Bitmap64(self.0.wrapping_sub(rhs))
     }
 
-    /// Create a new bitmap that has its bits set from `begin` (inclusive) to `end` (exclusive).
-    /// If begin is greater than the map length or end is 0, will return a bitmap with all bits set to
-    /// the opposite of value.
+    /// Multiplies the bitmap's value by `rhs`, wrapping around at the boundary of the type
+    /// rather than panicking on overflow.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::Bitmap64;
     ///
-    /// let a = Bitmap64::create_bit_mask(3, 7, true);
-    /// assert_eq!(*a, 0b1111000);
-    ///
-    /// let b = Bitmap64::create_bit_mask(3, 6, false); // Results in 1..1000111
-    /// assert_eq!(b, Bitmap64::new(true) ^ 0b111000);
+    /// assert_eq!(Bitmap64::new(true).wrapping_mul(2), Bitmap64::new(true) - 1);
     /// ```
-    pub fn create_bit_mask(begin: usize, end: usize, value: bool) -> Bitmap64 {
-        if value {
-            if begin >= Bitmap64::MAP_LENGTH || end < 1 {
-                Bitmap64(0)
-            } else if end >= Bitmap64::MAP_LENGTH {
-                Bitmap64(u64::MAX << begin)
-            } else {
-                Bitmap64(u64::MAX << begin & u64::MAX >> Bitmap64::MAP_LENGTH - end)
-            }
-        } else {
-            !Bitmap64::create_bit_mask(begin, end, true)
-        }
+    pub fn wrapping_mul(self, rhs: u64) -> Bitmap64 {
+        Bitmap64(self.0.wrapping_mul(rhs))
     }
 
-    /// Creates a new, empty `Bitmap64`, and sets the desired index before returning.
+    /// Adds `rhs` to the bitmap's value, returning `None` on overflow.
+    ///
+    /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::Bitmap64;
     ///
-    /// let a = Bitmap64::from_set(5).unwrap();
+    /// assert_eq!(Bitmap64::from(1).checked_add(2), Some(Bitmap64::from(3)));
+    /// assert_eq!(Bitmap64::new(true).checked_add(1), None);
+    /// ```
+    pub fn checked_add(self, rhs: u64) -> Option<Bitmap64> {
+        self.0.checked_add(rhs).map(Bitmap64)
+    }
+
+    /// Subtracts `rhs` from the bitmap's value, returning `None` on underflow.
     ///
-    /// // The above is equivalent to:
+    /// ## Example
     ///
-    /// let mut b = Bitmap64::from(0);
-    /// b.set(5, true);
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
     ///
-    /// assert_eq!(a, b);
+    /// assert_eq!(Bitmap64::from(3).checked_sub(2), Some(Bitmap64::from(1)));
+    /// assert_eq!(Bitmap64::from(1).checked_sub(2), None);
     /// ```
-    pub fn from_set(index: usize) -> Option<Bitmap64> {
-        if index >= Bitmap64::MAP_LENGTH {
-            return None;
-        }
-
-        let mut bitmap = Bitmap64::default();
-        bitmap.set(index, true).unwrap();
-        Some(bitmap)
+    pub fn checked_sub(self, rhs: u64) -> Option<Bitmap64> {
+        self.0.checked_sub(rhs).map(Bitmap64)
     }
 
-    /// Sets the desired index, to the value provided. Note that indexing starts
-    /// at 0.
+    /// Multiplies the bitmap's value by `rhs`, returning `None` on overflow.
     ///
-    /// ## Returns
+    /// ## Example
     ///
-    /// Returns a `Result` based on the outcome. If an `Err<String>` was returned,
-    /// it was because an out-of-bounds index was attempted to be set. In that
-    /// case the bitmap's state remains unchanged.
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(Bitmap64::from(3).checked_mul(2), Some(Bitmap64::from(6)));
+    /// assert_eq!(Bitmap64::new(true).checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(self, rhs: u64) -> Option<Bitmap64> {
+        self.0.checked_mul(rhs).map(Bitmap64)
+    }
+
+    /// Divides the bitmap's value by `rhs`, returning `None` if `rhs` is 0.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::Bitmap64;
     ///
-    /// let mut bitmap = Bitmap64::default();
-    /// assert_eq!(*bitmap, 0);
-    ///
-    /// bitmap.set(4, true);
-    /// assert_eq!(*bitmap, 16);
+    /// assert_eq!(Bitmap64::from(6).checked_div(2), Some(Bitmap64::from(3)));
+    /// assert_eq!(Bitmap64::from(6).checked_div(0), None);
     /// ```
-    pub fn set(&mut self, index: usize, value: bool) -> Result<(), String> {
-        if index >= Bitmap64::MAP_LENGTH {
-            return Err(String::from(
-                "Tried to set bit that's out of range of the bitmap (range: ",
-            ) + &Bitmap64::MAP_LENGTH.to_string()
-                + ", index: "
-                + &index.to_string()
-                + ")");
-        }
-
-        if value {
-            let mask = 1 << index;
-            self.0 |= mask;
-        } else {
-            let mask = u64::MAX - (1 << index);
-            self.0 &= mask;
-        }
+    pub fn checked_div(self, rhs: u64) -> Option<Bitmap64> {
+        self.0.checked_div(rhs).map(Bitmap64)
+    }
 
-        Ok(())
+    /// Adds `rhs` to the bitmap's value, stopping at `u64::MAX` rather than overflowing.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(*Bitmap64::from(1).saturating_add(2), 3);
+    /// assert_eq!(Bitmap64::new(true).saturating_add(1), Bitmap64::new(true));
+    /// ```
+    pub fn saturating_add(self, rhs: u64) -> Bitmap64 {
+        Bitmap64(self.0.saturating_add(rhs))
     }
 
-    /// Set bits from begin (inclusive) to end (exclusive) to the given value.
+    /// Subtracts `rhs` from the bitmap's value, stopping at 0 rather than underflowing.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::Bitmap64;
     ///
-    /// let mut bitmap = Bitmap64::default();
-    /// assert_eq!(*bitmap, 0);
+    /// assert_eq!(*Bitmap64::from(3).saturating_sub(2), 1);
+    /// assert_eq!(*Bitmap64::from(1).saturating_sub(2), 0);
+    /// ```
+    pub fn saturating_sub(self, rhs: u64) -> Bitmap64 {
+        Bitmap64(self.0.saturating_sub(rhs))
+    }
+
+    /// Adds the value of another bitmap to this one's, stopping at `u64::MAX` rather than
+    /// overflowing.
     ///
-    /// bitmap.set_range(2, 7, true);
-    /// assert_eq!(*bitmap, 0b1111100);
+    /// ## Example
     ///
-    /// bitmap.set_range(3, 5, false);
-    /// assert_eq!(*bitmap, 0b1100100);
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// let full = Bitmap64::new(true);
+    /// assert_eq!(full.saturating_add_bitmap(Bitmap64::from(1)), full);
     /// ```
-    pub fn set_range(&mut self, begin: usize, end: usize, value: bool) {
-        if value {
-            *self |= Bitmap64::create_bit_mask(begin, end, true);
-        } else {
-            *self &= Bitmap64::create_bit_mask(begin, end, false);
-        }
+    pub fn saturating_add_bitmap(self, rhs: Bitmap64) -> Bitmap64 {
+        self.saturating_add(rhs.0)
     }
 
-    /// Gets the bit at the given index. Note that indexing starts at 0.
+    /// Subtracts the value of another bitmap from this one's, stopping at 0 rather than
+    /// underflowing.
     ///
-    /// ## Returns
+    /// ## Example
     ///
-    /// Returns a `Result` based on the outcome.
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
     ///
-    /// If `Ok<bool>` is returned, then the contained value in ok is the state
-    /// of the given bit
+    /// let a = Bitmap64::from(1);
+    /// assert_eq!(a.saturating_sub_bitmap(Bitmap64::from(2)), Bitmap64::default());
+    /// ```
+    pub fn saturating_sub_bitmap(self, rhs: Bitmap64) -> Bitmap64 {
+        self.saturating_sub(rhs.0)
+    }
+
+    /// Adds `rhs` to the bitmap's value, returning the wrapped result along with a flag
+    /// indicating whether an overflow occurred.
     ///
-    /// If an `Err<String>` was returned, it was because you tried to get
-    /// an out-of-bounds index.
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(Bitmap64::from(1).overflowing_add(2), (Bitmap64::from(3), false));
+    /// assert_eq!(Bitmap64::new(true).overflowing_add(1), (Bitmap64::default(), true));
+    /// ```
+    pub fn overflowing_add(self, rhs: u64) -> (Bitmap64, bool) {
+        let (value, overflowed) = self.0.overflowing_add(rhs);
+        (Bitmap64(value), overflowed)
+    }
+
+    /// Subtracts `rhs` from the bitmap's value, returning the wrapped result along with a flag
+    /// indicating whether an underflow occurred.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::Bitmap64;
     ///
-    /// let bitmap = Bitmap64::from(0b1010);
-    /// assert_eq!(bitmap.get(2).unwrap(), false);
-    /// assert_eq!(bitmap.get(3).unwrap(), true);
+    /// assert_eq!(Bitmap64::from(3).overflowing_sub(2), (Bitmap64::from(1), false));
+    /// assert_eq!(Bitmap64::default().overflowing_sub(1), (Bitmap64::new(true), true));
     /// ```
-    pub fn get(&self, index: usize) -> Result<bool, String> {
-        if index >= Bitmap64::MAP_LENGTH {
-            return Err(String::from(
-                "Tried to get bit that's out of range of the bitmap (range: ",
-            ) + &Bitmap64::MAP_LENGTH.to_string()
-                + ", index: "
-                + &index.to_string()
-                + ")");
-        }
+    pub fn overflowing_sub(self, rhs: u64) -> (Bitmap64, bool) {
+        let (value, overflowed) = self.0.overflowing_sub(rhs);
+        (Bitmap64(value), overflowed)
+    }
 
-        let mask = 1 << index;
-        Ok(self.0 & mask > 0)
+    /// Multiplies the bitmap's value by `rhs`, returning the wrapped result along with a flag
+    /// indicating whether an overflow occurred.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap64;
+    ///
+    /// assert_eq!(Bitmap64::from(3).overflowing_mul(2), (Bitmap64::from(6), false));
+    /// assert!(Bitmap64::new(true).overflowing_mul(2).1);
+    /// ```
+    pub fn overflowing_mul(self, rhs: u64) -> (Bitmap64, bool) {
+        let (value, overflowed) = self.0.overflowing_mul(rhs);
+        (Bitmap64(value), overflowed)
     }
 }
 
+/// Shows the bitmap in binary, padded with leading zeros to the full width of the bitmap so that
+/// every bit's position is unambiguous.
 impl Display for Bitmap64 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{:b}", self.0)
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{:0width$b}", self.0, width = Bitmap64::MAP_LENGTH)
     }
 }
 
 impl Debug for Bitmap64 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "Bitmap64({:X})", self.0)
     }
 }
 
+/// Formats the bitmap's value exactly as `u64` does, so flags such as `#` and a width like
+/// `{:08b}` behave as expected.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::Bitmap64;
+///
+/// let bitmap = Bitmap64::from(0b101);
+/// assert_eq!(format!("{:b}", bitmap), "101");
+/// assert_eq!(format!("{:#06b}", bitmap), "0b0101");
+/// ```
+impl Binary for Bitmap64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Binary::fmt(&self.0, f)
+    }
+}
+
 impl From<u64> for Bitmap64 {
     fn from(value: u64) -> Self {
         Bitmap64(value)
     }
 }
 
+/// Parses a string of `'0'` and `'1'` characters, with the most significant bit first. Strings
+/// shorter than the bitmap's capacity are padded with leading zeros.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::Bitmap64;
+///
+/// let bitmap: Bitmap64 = "1011".parse().unwrap();
+/// assert_eq!(*bitmap, 0b1011);
+///
+/// assert!("1021".parse::<Bitmap64>().is_err());
+/// ```
+impl FromStr for Bitmap64 {
+    type Err = ParseBitmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseBitmapError::Empty);
+        }
+
+        if s.len() > Bitmap64::MAP_LENGTH {
+            return Err(ParseBitmapError::TooLong {
+                length: s.len(),
+                capacity: Bitmap64::MAP_LENGTH,
+            });
+        }
+
+        let mut value = 0;
+        for (index, character) in s.char_indices() {
+            let bit = match character {
+                '0' => 0,
+                '1' => 1,
+                _ => return Err(ParseBitmapError::InvalidDigit { character, index }),
+            };
+            value = (value << 1) | bit;
+        }
+
+        Ok(Bitmap64(value))
+    }
+}
+
+/// Interprets the bytes as the bitmap's value in little-endian byte order, the same as
+/// `Bitmap64::from_le_bytes`.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::Bitmap64;
+///
+/// let mut bytes = Bitmap64::default().to_le_bytes();
+/// bytes[0] = 0b101;
+/// assert_eq!(*Bitmap64::from(bytes), 0b101);
+/// ```
+impl From<[u8; mem::size_of::<u64>()]> for Bitmap64 {
+    fn from(bytes: [u8; mem::size_of::<u64>()]) -> Self {
+        Bitmap64::from_le_bytes(bytes)
+    }
+}
+
+/// Produces the bitmap's value in little-endian byte order, the same as
+/// `Bitmap64::to_le_bytes`.
+impl From<Bitmap64> for [u8; mem::size_of::<u64>()] {
+    fn from(bitmap: Bitmap64) -> Self {
+        bitmap.to_le_bytes()
+    }
+}
+
+/// Collects bits starting from index 0. Any bits beyond the map length are ignored.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::Bitmap64;
+///
+/// let bitmap: Bitmap64 = vec![true, false, true, true].into_iter().collect();
+/// assert_eq!(*bitmap, 0b1101);
+/// ```
+impl FromIterator<bool> for Bitmap64 {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bitmap = Bitmap64::default();
+
+        for (index, bit) in iter.into_iter().take(Bitmap64::MAP_LENGTH).enumerate() {
+            if bit {
+                bitmap.0 |= 1 << index;
+            }
+        }
+
+        bitmap
+    }
+}
+
 impl BitmapSize for Bitmap64 {
     const MAP_LENGTH: usize = mem::size_of::<u64>() * 8;
 }
 
+impl BitmapOps for Bitmap64 {
+    fn get(&self, index: usize) -> Result<bool, BitmapError> {
+        Bitmap64::get(self, index)
+    }
+
+    fn set(&mut self, index: usize, value: bool) -> Result<(), BitmapError> {
+        Bitmap64::set(self, index, value)
+    }
+
+    fn count_ones(&self) -> usize {
+        Bitmap64::count_ones(self) as usize
+    }
+
+    fn is_empty(&self) -> bool {
+        Bitmap64::is_empty(self)
+    }
+
+    fn is_full(&self) -> bool {
+        Bitmap64::is_full(self)
+    }
+}
+
 // Traits implementing bitwise operations between Bitmaps of the same type
 
 impl BitAnd for Bitmap64 {
@@ -500,3 +1974,127 @@ impl Deref for Bitmap64 {
         &self.0
     }
 }
+
+// Indexing
+
+/// Gets the bit at the given index, panicking if it's out of bounds as slice indexing does. Use
+/// `get()` to handle an out-of-bounds index instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::Bitmap64;
+///
+/// let bitmap = Bitmap64::from(0b1010);
+/// assert!(bitmap[3]);
+/// assert!(!bitmap[2]);
+/// ```
+impl Index<usize> for Bitmap64 {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if self.get(index).expect("bitmap index out of bounds") {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+// Iterators
+
+/// An iterator over the indices of the set bits of a `Bitmap64`, in ascending order.
+///
+/// Created by `Bitmap64::set_bits()`.
+#[derive(Clone, Debug)]
+pub struct Bitmap64SetBits(u64);
+
+impl Iterator for Bitmap64SetBits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let index = self.0.trailing_zeros() as usize;
+        // Clears the lowest set bit
+        self.0 &= self.0 - 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.0.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bitmap64SetBits {}
+
+/// An iterator over the indices of the cleared bits of a `Bitmap64`, in ascending order.
+///
+/// Created by `Bitmap64::clear_bits()`.
+#[derive(Clone, Debug)]
+pub struct Bitmap64ClearBits(Bitmap64SetBits);
+
+impl Iterator for Bitmap64ClearBits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Bitmap64ClearBits {}
+
+/// An iterator over every bit of a `Bitmap64` as a `bool`, starting from index 0.
+///
+/// Created by `Bitmap64::bits()`.
+#[derive(Clone, Debug)]
+pub struct Bitmap64Bits {
+    bitmap: u64,
+    index: usize,
+}
+
+impl Iterator for Bitmap64Bits {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= Bitmap64::MAP_LENGTH {
+            return None;
+        }
+
+        let bit = (self.bitmap >> self.index) & 1 == 1;
+        self.index += 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = Bitmap64::MAP_LENGTH - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bitmap64Bits {}
+
+impl IntoIterator for Bitmap64 {
+    type Item = bool;
+    type IntoIter = Bitmap64Bits;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits()
+    }
+}
+
+impl IntoIterator for &Bitmap64 {
+    type Item = bool;
+    type IntoIter = Bitmap64Bits;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits()
+    }
+}
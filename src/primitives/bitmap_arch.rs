@@ -1,14 +1,19 @@
-use super::BitmapSize;
+use super::{BitmapOps, BitmapSize};
+use crate::{BitmapError, ParseBitmapError};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
 use core::fmt::Formatter;
-use serde::{Deserialize, Serialize};
-use std::{
-    fmt::{Debug, Display},
+use core::{
+    fmt::{Binary, Debug, Display},
+    iter::FromIterator,
     mem,
     ops::{
         Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, Div,
-        DivAssign, Mul, MulAssign, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+        DivAssign, Index, Mul, MulAssign, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
     },
+    str::FromStr,
 };
+use serde::{Deserialize, Serialize};
 
 /// A bitmap of length usize.
 ///
@@ -53,199 +58,1668 @@ impl BitmapArch {
         self.0
     }
 
-    /// Creates a new bitmap with all bits set to the given value.
+    /// Returns a reference to the underlying integer.
+    pub fn inner(&self) -> &usize {
+        &self.0
+    }
+
+    /// Consumes the bitmap, returning the underlying integer.
+    pub fn into_inner(self) -> usize {
+        self.0
+    }
+
+    /// Returns the bitmap's value as a byte array in big-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bytes = BitmapArch::from(1).to_be_bytes();
+    /// assert_eq!(bytes[bytes.len() - 1], 1);
+    /// assert!(bytes[..bytes.len() - 1].iter().all(|&byte| byte == 0));
+    /// ```
+    pub fn to_be_bytes(&self) -> [u8; mem::size_of::<usize>()] {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates a bitmap from its value as a byte array in big-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1011);
+    /// assert_eq!(BitmapArch::from_be_bytes(bitmap.to_be_bytes()), bitmap);
+    /// ```
+    pub fn from_be_bytes(bytes: [u8; mem::size_of::<usize>()]) -> BitmapArch {
+        BitmapArch(usize::from_be_bytes(bytes))
+    }
+
+    /// Returns the bitmap's value as a byte array in little-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bytes = BitmapArch::from(1).to_le_bytes();
+    /// assert_eq!(bytes[0], 1);
+    /// assert!(bytes[1..].iter().all(|&byte| byte == 0));
+    /// ```
+    pub fn to_le_bytes(&self) -> [u8; mem::size_of::<usize>()] {
+        self.0.to_le_bytes()
+    }
+
+    /// Creates a bitmap from its value as a byte array in little-endian byte order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1011);
+    /// assert_eq!(BitmapArch::from_le_bytes(bitmap.to_le_bytes()), bitmap);
+    /// ```
+    pub fn from_le_bytes(bytes: [u8; mem::size_of::<usize>()]) -> BitmapArch {
+        BitmapArch(usize::from_le_bytes(bytes))
+    }
+
+    /// Parses a hexadecimal string, with an optional `0x` prefix and either case of `a` to `f`.
+    /// Strings with fewer digits than the bitmap can hold are padded with leading zeros.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(*BitmapArch::from_hex_str("0xA5").unwrap(), 0xA5);
+    /// assert_eq!(*BitmapArch::from_hex_str("1f").unwrap(), 0x1F);
+    /// assert!(BitmapArch::from_hex_str("0xG").is_err());
+    /// ```
+    pub fn from_hex_str(s: &str) -> Result<BitmapArch, ParseBitmapError> {
+        let (prefix_length, digits) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(digits) => (2, digits),
+            None => (0, s),
+        };
+
+        if digits.is_empty() {
+            return Err(ParseBitmapError::Empty);
+        }
+
+        if digits.len() > BitmapArch::MAP_LENGTH / 4 {
+            return Err(ParseBitmapError::TooLong {
+                length: digits.len(),
+                capacity: BitmapArch::MAP_LENGTH / 4,
+            });
+        }
+
+        if let Some((index, character)) = digits
+            .char_indices()
+            .find(|(_, character)| !character.is_ascii_hexdigit())
+        {
+            return Err(ParseBitmapError::InvalidDigit {
+                character,
+                index: prefix_length + index,
+            });
+        }
+
+        // Every digit is valid and there are few enough of them to fit, so this can't fail
+        Ok(BitmapArch(usize::from_str_radix(digits, 16).unwrap()))
+    }
+
+    /// Creates a new bitmap with all bits set to the given value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::new(true);
+    /// assert_eq!(*a, usize::MAX);
+    ///
+    /// let b = BitmapArch::new(false);
+    /// assert_eq!(*b, 0);
+    /// ```
+    pub fn new(value: bool) -> BitmapArch {
+        BitmapArch(if value { usize::MAX } else { 0 })
+    }
+
+    /// Create a new bitmap that has its bits set from `begin` (inclusive) to `end` (exclusive).
+    /// If begin is greater than the map length or end is 0, will return a bitmap with all bits set to
+    /// the opposite of value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::create_bit_mask(3, 7, true);
+    /// assert_eq!(*a, 0b1111000);
+    ///
+    /// let b = BitmapArch::create_bit_mask(3, 6, false); // Results in 1..1000111
+    /// assert_eq!(b, BitmapArch::new(true) ^ 0b111000);
+    /// ```
+    pub fn create_bit_mask(begin: usize, end: usize, value: bool) -> BitmapArch {
+        if value {
+            if begin >= BitmapArch::MAP_LENGTH || end < 1 {
+                BitmapArch(0)
+            } else if end >= BitmapArch::MAP_LENGTH {
+                BitmapArch(usize::MAX << begin)
+            } else {
+                BitmapArch((usize::MAX << begin) & (usize::MAX >> (BitmapArch::MAP_LENGTH - end)))
+            }
+        } else {
+            !BitmapArch::create_bit_mask(begin, end, true)
+        }
+    }
+
+    /// Creates a new bitmap with the lowest `n` bits set, and all others cleared. This is
+    /// equivalent to `BitmapArch::create_bit_mask(0, n, true)`. If `n` is at least the map
+    /// length, all bits will be set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::spread(5);
+    /// assert_eq!(*a, 0b11111);
+    ///
+    /// let b = BitmapArch::spread(BitmapArch::capacity());
+    /// assert_eq!(*b, usize::MAX);
+    /// ```
+    pub fn spread(n: usize) -> BitmapArch {
+        BitmapArch::create_bit_mask(0, n, true)
+    }
+
+    /// Creates a new bitmap with alternating bits, `...0101` if `start_with_one` is true
+    /// (the bit at index 0 is set), or `...1010` otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::from_alternating(true);
+    /// assert_eq!(*a & 0b1111, 0b0101);
+    ///
+    /// let b = BitmapArch::from_alternating(false);
+    /// assert_eq!(*b & 0b1111, 0b1010);
+    /// ```
+    pub fn from_alternating(start_with_one: bool) -> BitmapArch {
+        // Dividing all 1's by 0b11 leaves 0b0101...0101
+        let pattern = usize::MAX / 3;
+
+        if start_with_one {
+            BitmapArch(pattern)
+        } else {
+            BitmapArch(!pattern)
+        }
+    }
+
+    /// Creates a new bitmap with every byte set to the given byte.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from_repeating_byte(0b10100101);
+    /// assert_eq!(*bitmap & 0xFF, 0b10100101);
+    /// assert_eq!(BitmapArch::from_repeating_byte(0xFF), BitmapArch::new(true));
+    /// ```
+    pub fn from_repeating_byte(byte: u8) -> BitmapArch {
+        BitmapArch(usize::from_ne_bytes([byte; mem::size_of::<usize>()]))
+    }
+
+    /// Creates a new bitmap taking the bits of `value` only where `mask` is set, and 0
+    /// everywhere else. This is the same as `mask & value`, but names the intent of
+    /// selecting bits from a value, as is common when accessing hardware registers.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mask = BitmapArch::from(0b1100);
+    /// let value = BitmapArch::from(0b1010);
+    /// assert_eq!(*BitmapArch::from_mask_and_value(mask, value), 0b1000);
+    /// ```
+    pub fn from_mask_and_value(mask: BitmapArch, value: BitmapArch) -> BitmapArch {
+        mask & value
+    }
+
+    /// Creates a new, empty `BitmapArch`, and sets the desired index before returning.
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::from_set(5).unwrap();
+    ///
+    /// // The above is equivalent to:
+    ///
+    /// let mut b = BitmapArch::from(0);
+    /// b.set(5, true);
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn from_set(index: usize) -> Result<BitmapArch, BitmapError> {
+        let mut bitmap = BitmapArch::default();
+        bitmap.set(index, true)?;
+        Ok(bitmap)
+    }
+
+    /// Creates a new bitmap with each of the given indices set.
+    ///
+    /// **The indices must be sorted in ascending order.** Since only the last index can be
+    /// the largest, it's the only one that needs to be checked against the bounds of the
+    /// bitmap, and the rest can be set in a single linear scan. Repeated indices are fine.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if the indices aren't sorted in ascending order, or if
+    /// any of them is out of bounds. The ordering is checked in every build rather than with a
+    /// debug-mode assertion, so unsorted input gives `BitmapError::UnsortedIndices` instead of
+    /// a panic.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::from_sorted_indices(&[0, 3, 5]).unwrap();
+    /// assert_eq!(*a, 0b101001);
+    ///
+    /// assert!(BitmapArch::from_sorted_indices(&[3, 0, 5]).is_err());
+    /// ```
+    pub fn from_sorted_indices(indices: &[usize]) -> Result<BitmapArch, BitmapError> {
+        let last = match indices.last() {
+            Some(&last) => last,
+            None => return Ok(BitmapArch::default()),
+        };
+
+        if last >= BitmapArch::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index: last,
+                capacity: BitmapArch::MAP_LENGTH,
+            });
+        }
+
+        let mut bitmap = BitmapArch::default();
+        let mut previous = 0;
+
+        for &index in indices {
+            // Any index larger than the last one also means the indices aren't sorted
+            if index < previous || index > last {
+                return Err(BitmapError::UnsortedIndices { index, previous });
+            }
+
+            bitmap.0 |= 1 << index;
+            previous = index;
+        }
+
+        Ok(bitmap)
+    }
+
+    /// Creates a new bitmap with each of the given indices set, silently ignoring any that
+    /// are out of bounds. See `from_indices_checked()` for a version that doesn't.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::from_indices(vec![5, 0, 3]);
+    /// assert_eq!(*a, 0b101001);
+    ///
+    /// // Round trips with set_bits()
+    /// assert_eq!(BitmapArch::from_indices(a.set_bits()), a);
+    /// ```
+    pub fn from_indices(indices: impl IntoIterator<Item = usize>) -> BitmapArch {
+        let mut bitmap = BitmapArch::default();
+
+        for index in indices {
+            // Out-of-bounds indices are meant to be ignored here
+            let _ = bitmap.set(index, true);
+        }
+
+        bitmap
+    }
+
+    /// Creates a new bitmap with each of the given indices set.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` as soon as an out-of-bounds index is found.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::from_indices_checked(vec![5, 0, 3]).unwrap();
+    /// assert_eq!(*a, 0b101001);
+    ///
+    /// assert!(BitmapArch::from_indices_checked(vec![0, BitmapArch::capacity()]).is_err());
+    /// ```
+    pub fn from_indices_checked(
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Result<BitmapArch, BitmapError> {
+        let mut bitmap = BitmapArch::default();
+
+        for index in indices {
+            bitmap.set(index, true)?;
+        }
+
+        Ok(bitmap)
+    }
+
+    /// Sets the desired index, to the value provided. Note that indexing starts
+    /// at 0.
+    ///
+    /// ## Returns
+    ///
+    /// Returns a `Result` based on the outcome. If an `Err<BitmapError>` was returned,
+    /// it was because an out-of-bounds index was attempted to be set. In that
+    /// case the bitmap's state remains unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mut bitmap = BitmapArch::default();
+    /// assert_eq!(*bitmap, 0);
+    ///
+    /// bitmap.set(4, true);
+    /// assert_eq!(*bitmap, 16);
+    /// ```
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), BitmapError> {
+        if index >= BitmapArch::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: BitmapArch::MAP_LENGTH,
+            });
+        }
+
+        if value {
+            let mask = 1 << index;
+            self.0 |= mask;
+        } else {
+            let mask = usize::MAX - (1 << index);
+            self.0 &= mask;
+        }
+
+        Ok(())
+    }
+
+    /// Set bits from begin (inclusive) to end (exclusive) to the given value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mut bitmap = BitmapArch::default();
+    /// assert_eq!(*bitmap, 0);
+    ///
+    /// bitmap.set_range(2, 7, true);
+    /// assert_eq!(*bitmap, 0b1111100);
+    ///
+    /// bitmap.set_range(3, 5, false);
+    /// assert_eq!(*bitmap, 0b1100100);
+    /// ```
+    pub fn set_range(&mut self, begin: usize, end: usize, value: bool) {
+        if value {
+            *self |= BitmapArch::create_bit_mask(begin, end, true);
+        } else {
+            *self &= BitmapArch::create_bit_mask(begin, end, false);
+        }
+    }
+
+    /// Flips the bit at the given index. Note that indexing starts at 0.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if the index is out of bounds, in which case the
+    /// bitmap's state remains unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mut bitmap = BitmapArch::from(0b1010);
+    ///
+    /// bitmap.toggle(1).unwrap();
+    /// assert_eq!(*bitmap, 0b1000);
+    ///
+    /// bitmap.toggle(0).unwrap();
+    /// assert_eq!(*bitmap, 0b1001);
+    /// ```
+    pub fn toggle(&mut self, index: usize) -> Result<(), BitmapError> {
+        if index >= BitmapArch::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: BitmapArch::MAP_LENGTH,
+            });
+        }
+
+        self.0 ^= 1 << index;
+        Ok(())
+    }
+
+    /// Flips the bits from begin (inclusive) to end (exclusive).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mut bitmap = BitmapArch::from(0b1100);
+    ///
+    /// bitmap.toggle_range(1, 4);
+    /// assert_eq!(*bitmap, 0b0010);
+    /// ```
+    pub fn toggle_range(&mut self, begin: usize, end: usize) {
+        *self ^= BitmapArch::create_bit_mask(begin, end, true);
+    }
+
+    /// Swaps the bits at indices `i` and `j`. Nothing is written if both bits are equal.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if either index is out of bounds, in which case the
+    /// bitmap's state remains unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mut bitmap = BitmapArch::from(0b0001);
+    ///
+    /// bitmap.swap_bits(0, 3).unwrap();
+    /// assert_eq!(*bitmap, 0b1000);
+    /// ```
+    pub fn swap_bits(&mut self, i: usize, j: usize) -> Result<(), BitmapError> {
+        if self.get(i)? != self.get(j)? {
+            self.0 ^= (1 << i) | (1 << j);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the bit at the given index. Note that indexing starts at 0.
+    ///
+    /// ## Returns
+    ///
+    /// Returns a `Result` based on the outcome.
+    ///
+    /// If `Ok<bool>` is returned, then the contained value in ok is the state
+    /// of the given bit
+    ///
+    /// If an `Err<BitmapError>` was returned, it was because you tried to get
+    /// an out-of-bounds index.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1010);
+    /// assert_eq!(bitmap.get(2).unwrap(), false);
+    /// assert_eq!(bitmap.get(3).unwrap(), true);
+    /// ```
+    pub fn get(&self, index: usize) -> Result<bool, BitmapError> {
+        if index >= BitmapArch::MAP_LENGTH {
+            return Err(BitmapError::IndexOutOfBounds {
+                index,
+                capacity: BitmapArch::MAP_LENGTH,
+            });
+        }
+
+        let mask = 1 << index;
+        Ok(self.0 & mask > 0)
+    }
+
+    /// Gets the bits from begin (inclusive) to end (exclusive) packed into the low bits of a
+    /// `u64`, with bit 0 of the result being the bit at `begin`. Useful for reading integer
+    /// fields out of a packed bitmap.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if `begin > end`, if `end` is beyond the bitmap's length,
+    /// or if the range is wider than 64 bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1011_0110);
+    /// assert_eq!(bitmap.get_range(1, 4).unwrap(), 0b011);
+    /// assert_eq!(bitmap.get_range(4, 8).unwrap(), 0b1011);
+    /// ```
+    #[allow(clippy::unnecessary_cast)]
+    pub fn get_range(&self, begin: usize, end: usize) -> Result<u64, BitmapError> {
+        if begin > end || end > BitmapArch::MAP_LENGTH {
+            return Err(BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity: BitmapArch::MAP_LENGTH,
+            });
+        }
+
+        let width = end - begin;
+        if width > 64 {
+            return Err(BitmapError::RangeTooWide {
+                width,
+                max_width: 64,
+            });
+        }
+
+        if width == 0 {
+            return Ok(0);
+        }
+
+        Ok((self.0 >> begin) as u64 & (u64::MAX >> (64 - width)))
+    }
+
+    /// Writes the low `end - begin` bits of `value` into the bitmap from begin (inclusive) to
+    /// end (exclusive), with bit 0 of `value` going to `begin`. Any bits of `value` above the
+    /// width of the range are ignored. This is the inverse of `get_range()`.
+    ///
+    /// ## Returns
+    ///
+    /// Returns an `Err<BitmapError>` if `begin > end`, if `end` is beyond the bitmap's length,
+    /// or if the range is wider than 64 bits, in which case the bitmap's state remains
+    /// unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mut bitmap = BitmapArch::from(0b1000_0001);
+    /// bitmap.set_bits_from_value(1, 5, 0b1_0110).unwrap();
+    /// assert_eq!(*bitmap, 0b1000_1101);
+    /// assert_eq!(bitmap.get_range(1, 5).unwrap(), 0b0110);
+    /// ```
+    #[allow(clippy::unnecessary_cast)]
+    pub fn set_bits_from_value(
+        &mut self,
+        begin: usize,
+        end: usize,
+        value: u64,
+    ) -> Result<(), BitmapError> {
+        if begin > end || end > BitmapArch::MAP_LENGTH {
+            return Err(BitmapError::RangeOutOfBounds {
+                begin,
+                end,
+                capacity: BitmapArch::MAP_LENGTH,
+            });
+        }
+
+        let width = end - begin;
+        if width > 64 {
+            return Err(BitmapError::RangeTooWide {
+                width,
+                max_width: 64,
+            });
+        }
+
+        if width == 0 {
+            return Ok(());
+        }
+
+        let field = value & (u64::MAX >> (64 - width));
+        self.set_range(begin, end, false);
+        self.0 |= (field as usize) << begin;
+        Ok(())
+    }
+
+    /// Gets the bit at the given index without checking that the index is in bounds.
+    ///
+    /// ## Safety
+    ///
+    /// Calling this with `index >= BitmapArch::capacity()` is undefined behaviour. Use `get()`
+    /// unless the index has already been checked and the bounds check is measurably slow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1010);
+    /// assert!(unsafe { bitmap.get_unchecked(3) });
+    /// assert!(!unsafe { bitmap.get_unchecked(2) });
+    /// ```
+    pub unsafe fn get_unchecked(&self, index: usize) -> bool {
+        debug_assert!(index < BitmapArch::MAP_LENGTH);
+        self.0 & (1 << index) > 0
+    }
+
+    /// Sets the bit at the given index to the value provided without checking that the index
+    /// is in bounds.
+    ///
+    /// ## Safety
+    ///
+    /// Calling this with `index >= BitmapArch::capacity()` is undefined behaviour. Use `set()`
+    /// unless the index has already been checked and the bounds check is measurably slow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let mut bitmap = BitmapArch::default();
+    /// unsafe { bitmap.set_unchecked(4, true) };
+    /// assert_eq!(*bitmap, 0b10000);
+    /// ```
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: bool) {
+        debug_assert!(index < BitmapArch::MAP_LENGTH);
+        if value {
+            self.0 |= 1 << index;
+        } else {
+            self.0 &= !(1 << index);
+        }
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the left, wrapping the truncated bits
+    /// around to the low end. As with the integer types, `n` is taken modulo the map length.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b101);
+    /// assert_eq!(*bitmap.rotate_left(2), 0b10100);
+    /// assert_eq!(bitmap.rotate_left(BitmapArch::capacity() as u32), bitmap);
+    /// ```
+    pub const fn rotate_left(&self, n: u32) -> BitmapArch {
+        BitmapArch(self.0.rotate_left(n))
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the right, wrapping the truncated bits
+    /// around to the high end. As with the integer types, `n` is taken modulo the map length.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b10100);
+    /// assert_eq!(*bitmap.rotate_right(2), 0b101);
+    /// assert_eq!(bitmap.rotate_right(BitmapArch::capacity() as u32), bitmap);
+    /// ```
+    pub const fn rotate_right(&self, n: u32) -> BitmapArch {
+        BitmapArch(self.0.rotate_right(n))
+    }
+
+    /// Reverses the order of the bits, so the bit at index 0 ends up at the highest index and
+    /// vice versa.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1);
+    /// assert_eq!(bitmap.reverse_bits(), BitmapArch::from_set(BitmapArch::capacity() - 1).unwrap());
+    /// ```
+    pub const fn reverse_bits(&self) -> BitmapArch {
+        BitmapArch(self.0.reverse_bits())
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the left, wrapping the truncated bits
+    /// around to the low end. Returns `None` if `n` isn't less than the map length, as
+    /// rotating by that much is more likely a bug than intended.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(1);
+    /// assert_eq!(bitmap.checked_rotate_left(3), Some(BitmapArch::from(0b1000)));
+    /// assert_eq!(bitmap.checked_rotate_left(BitmapArch::capacity()), None);
+    /// ```
+    pub fn checked_rotate_left(&self, n: usize) -> Option<BitmapArch> {
+        if n >= BitmapArch::MAP_LENGTH {
+            return None;
+        }
+
+        Some(self.rotate_left(n as u32))
+    }
+
+    /// Rotates the bits of the bitmap `n` places to the right, wrapping the truncated bits
+    /// around to the high end. Returns `None` if `n` isn't less than the map length, as
+    /// rotating by that much is more likely a bug than intended.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1000);
+    /// assert_eq!(bitmap.checked_rotate_right(3), Some(BitmapArch::from(1)));
+    /// assert_eq!(bitmap.checked_rotate_right(BitmapArch::capacity()), None);
+    /// ```
+    pub fn checked_rotate_right(&self, n: usize) -> Option<BitmapArch> {
+        if n >= BitmapArch::MAP_LENGTH {
+            return None;
+        }
+
+        Some(self.rotate_right(n as u32))
+    }
+
+    /// Shifts the bitmap `n` places to the left, filling the vacated low bits with `fill`
+    /// rather than always with 0's. Shifting by the map length or more shifts out every bit.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b101);
+    /// assert_eq!(*bitmap.shift_left_or(4, true), 0b1011111);
+    /// assert_eq!(*bitmap.shift_left_or(4, false), 0b1010000);
+    /// ```
+    pub fn shift_left_or(&self, n: usize, fill: bool) -> BitmapArch {
+        let shifted = if n >= BitmapArch::MAP_LENGTH {
+            BitmapArch(0)
+        } else {
+            *self << n
+        };
+
+        if fill {
+            shifted | BitmapArch::spread(n)
+        } else {
+            shifted
+        }
+    }
+
+    /// Shifts the bitmap `n` places to the right, filling the vacated high bits with `fill`
+    /// rather than always with 0's. Shifting by the map length or more shifts out every bit.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1010000);
+    /// assert_eq!(*bitmap.shift_right_or(4, false), 0b101);
+    /// assert_eq!(bitmap.shift_right_or(4, true), BitmapArch::from(0b101) | !(BitmapArch::new(true) >> 4));
+    /// ```
+    pub fn shift_right_or(&self, n: usize, fill: bool) -> BitmapArch {
+        let shifted = if n >= BitmapArch::MAP_LENGTH {
+            BitmapArch(0)
+        } else {
+            *self >> n
+        };
+
+        if fill {
+            shifted | !BitmapArch::spread(BitmapArch::MAP_LENGTH.saturating_sub(n))
+        } else {
+            shifted
+        }
+    }
+
+    /// Selects the bits of this bitmap at the positions set in `mask`, and packs them
+    /// contiguously into the low bits of the returned bitmap, preserving their order. This is
+    /// the parallel bit extract operation, the inverse of `pdep()`.
+    ///
+    /// Uses the BMI2 `PEXT` instruction when compiled for a target that supports it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b10110100);
+    ///
+    /// // Selects the bits at the even indices, 0, 1, 1 and 0 from lowest to highest
+    /// let packed = bitmap.pext(BitmapArch::from(0b01010101));
+    /// assert_eq!(packed, BitmapArch::from(0b0110));
+    /// ```
+    pub fn pext(&self, mask: BitmapArch) -> BitmapArch {
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[allow(clippy::unnecessary_cast)]
+        {
+            if mem::size_of::<usize>() <= mem::size_of::<u64>() {
+                // Safe as the bmi2 target feature is guaranteed to be enabled here
+                let value = unsafe { core::arch::x86_64::_pext_u64(self.0 as u64, mask.0 as u64) };
+                return BitmapArch(value as usize);
+            }
+        }
+
+        let mut value = 0;
+        let mut remaining = mask.0;
+        let mut position = 0;
+
+        while remaining != 0 {
+            let index = remaining.trailing_zeros();
+            if (self.0 >> index) & 1 == 1 {
+                value |= 1 << position;
+            }
+            remaining &= remaining - 1;
+            position += 1;
+        }
+
+        BitmapArch(value)
+    }
+
+    /// Scatters the low bits of `src` into the positions set in `mask`, in order, leaving every
+    /// other bit cleared. This is the parallel bit deposit operation, the inverse of `pext()`.
+    ///
+    /// Uses the BMI2 `PDEP` instruction when compiled for a target that supports it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// // Deposits 0, 1, 1 and 0 from lowest to highest into the even indices
+    /// let bitmap = BitmapArch::pdep(BitmapArch::from(0b01010101), BitmapArch::from(0b0110));
+    /// assert_eq!(bitmap, BitmapArch::from(0b00010100));
+    /// ```
+    pub fn pdep(mask: BitmapArch, src: BitmapArch) -> BitmapArch {
+        #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+        #[allow(clippy::unnecessary_cast)]
+        {
+            if mem::size_of::<usize>() <= mem::size_of::<u64>() {
+                // Safe as the bmi2 target feature is guaranteed to be enabled here
+                let value = unsafe { core::arch::x86_64::_pdep_u64(src.0 as u64, mask.0 as u64) };
+                return BitmapArch(value as usize);
+            }
+        }
+
+        let mut value = 0;
+        let mut remaining = mask.0;
+        let mut position = 0;
+
+        while remaining != 0 {
+            let index = remaining.trailing_zeros();
+            if (src.0 >> position) & 1 == 1 {
+                value |= 1 << index;
+            }
+            remaining &= remaining - 1;
+            position += 1;
+        }
+
+        BitmapArch(value)
+    }
+
+    /// Selects the bits of this bitmap at the positions set in `mask`, and packs them
+    /// contiguously into the low bits of the returned value, preserving their order.
+    /// The result is returned as a raw `usize` rather than a bitmap, as it represents a
+    /// value rather than a set of bits. See `pext()` for a version returning a bitmap.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b10110100);
+    ///
+    /// // Selects the bits at the even indices, 0, 1, 1 and 0 from lowest to highest
+    /// let value = bitmap.pext_select(BitmapArch::from(0b01010101));
+    /// assert_eq!(value, 0b0110);
+    /// ```
+    pub fn pext_select(&self, mask: BitmapArch) -> usize {
+        self.pext(mask).0
+    }
+
+    /// Returns the next larger bitmap with the same number of set bits, or `None` if there is
+    /// none, which is the case when every set bit is already at the high end, or no bits are
+    /// set. Starting from the lowest `k` bits set, repeatedly calling this enumerates every
+    /// bitmap with `k` set bits in ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b0111);
+    /// assert_eq!(bitmap.next_permutation(), Some(BitmapArch::from(0b1011)));
+    /// assert_eq!(
+    ///     BitmapArch::from(0b1011).next_permutation(),
+    ///     Some(BitmapArch::from(0b1101))
+    /// );
+    /// assert_eq!(BitmapArch::new(true).next_permutation(), None);
+    /// ```
+    pub fn next_permutation(&self) -> Option<BitmapArch> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        // Gosper's hack: the lowest block of set bits has its top bit moved up by one, and
+        // the rest of the block is moved down to the lowest positions
+        let lowest = self.0 & self.0.wrapping_neg();
+        let ripple = self.0.checked_add(lowest)?;
+        let ones = ((ripple ^ self.0) >> 2) / lowest;
+        Some(BitmapArch(ripple | ones))
+    }
+
+    /// Returns the next smaller bitmap with the same number of set bits, or `None` if there
+    /// is none, which is the case when every set bit is already at the low end. This is the
+    /// inverse of `next_permutation()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1101);
+    /// assert_eq!(bitmap.prev_permutation(), Some(BitmapArch::from(0b1011)));
+    /// assert_eq!(BitmapArch::from(0b0111).prev_permutation(), None);
+    /// ```
+    pub fn prev_permutation(&self) -> Option<BitmapArch> {
+        // Inverting reverses the order of the bitmaps, so the previous bitmap is the inverse of
+        // the next bitmap after the inverse
+        BitmapArch(!self.0)
+            .next_permutation()
+            .map(|next| BitmapArch(!next.0))
+    }
+
+    /// Counts the number of runs of consecutive bits with the same value. This is always
+    /// one more than the number of times neighbouring bits change value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// // One run of 0's at the top, then a run of 1's, then another run of 0's
+    /// let bitmap = BitmapArch::from(0b00111100);
+    /// assert_eq!(bitmap.count_runs(), 3);
+    ///
+    /// assert_eq!(BitmapArch::default().count_runs(), 1);
+    /// ```
+    pub fn count_runs(&self) -> usize {
+        // Each set bit marks a change between a bit and the next one up, ignoring the top bit
+        // as it has nothing above it to compare against
+        let transitions = (self.0 ^ (self.0 >> 1)) & (usize::MAX >> 1);
+        transitions.count_ones() as usize + 1
+    }
+
+    /// Counts the number of bits that are set. This returns a `u32` like the integer types do;
+    /// `BitmapOps::count_ones()` gives the same count as a `usize`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b1011);
+    /// assert_eq!(bitmap.count_ones(), 3);
+    /// ```
+    pub fn count_ones(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Returns the parity of the bitmap, which is the XOR of all of its bits. In other words,
+    /// true if an odd number of bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert!(BitmapArch::from(0b1011).parity());
+    /// assert!(!BitmapArch::from(0b1001).parity());
+    /// ```
+    pub fn parity(&self) -> bool {
+        self.0.count_ones() & 1 == 1
+    }
+
+    /// Counts the number of positions at which the bits of the two bitmaps differ.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::from(0b1011);
+    /// let b = BitmapArch::from(0b0110);
+    /// assert_eq!(a.hamming_distance(&b), 3);
+    /// ```
+    pub fn hamming_distance(&self, other: &BitmapArch) -> usize {
+        (self.0 ^ other.0).count_ones() as usize
+    }
+
+    /// Returns true if every bit set in this bitmap is also set in `other`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let required = BitmapArch::from(0b0101);
+    /// let granted = BitmapArch::from(0b1101);
+    /// assert!(required.is_subset(&granted));
+    /// assert!(!granted.is_subset(&required));
+    /// ```
+    pub fn is_subset(&self, other: &BitmapArch) -> bool {
+        self.0 & other.0 == self.0
+    }
+
+    /// Returns true if every bit set in `other` is also set in this bitmap.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let granted = BitmapArch::from(0b1101);
+    /// assert!(granted.is_superset(&BitmapArch::from(0b0101)));
+    /// assert!(!granted.is_superset(&BitmapArch::from(0b0010)));
+    /// ```
+    pub fn is_superset(&self, other: &BitmapArch) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns true if the two bitmaps have no set bits in common.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let held = BitmapArch::from(0b0011);
+    /// assert!(held.is_disjoint(&BitmapArch::from(0b1100)));
+    /// assert!(!held.is_disjoint(&BitmapArch::from(0b0110)));
+    /// ```
+    pub fn is_disjoint(&self, other: &BitmapArch) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// Returns a bitmap of the bits set in this bitmap but not in `other`. This is the same as
+    /// `bitmap & !other`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let a = BitmapArch::from(0b1101);
+    /// let b = BitmapArch::from(0b0110);
+    /// assert_eq!(*a.difference(&b), 0b1001);
+    /// ```
+    pub fn difference(&self, other: &BitmapArch) -> BitmapArch {
+        BitmapArch(self.0 & !other.0)
+    }
+
+    /// Returns an iterator over the indices of all set bits, in ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b101001);
+    /// let indices: Vec<usize> = bitmap.set_bits().collect();
+    /// assert_eq!(indices, vec![0, 3, 5]);
+    /// ```
+    pub fn set_bits(&self) -> BitmapArchSetBits {
+        BitmapArchSetBits(self.0)
+    }
+
+    /// Returns an iterator over the indices of all cleared bits, in ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = !BitmapArch::from(0b101001);
+    /// let indices: Vec<usize> = bitmap.clear_bits().collect();
+    /// assert_eq!(indices, vec![0, 3, 5]);
+    /// ```
+    pub fn clear_bits(&self) -> BitmapArchClearBits {
+        BitmapArchClearBits(BitmapArchSetBits(!self.0))
+    }
+
+    /// Returns an iterator over every bit as a `bool`, starting from index 0.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b101);
+    /// let bits: Vec<bool> = bitmap.bits().take(4).collect();
+    /// assert_eq!(bits, vec![true, false, true, false]);
+    /// ```
+    pub fn bits(&self) -> BitmapArchBits {
+        BitmapArchBits {
+            bitmap: self.0,
+            index: 0,
+        }
+    }
+
+    /// Returns true if no bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert!(BitmapArch::default().is_empty());
+    /// assert!(!BitmapArch::from(0b100).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if any bit is set. This is the opposite of `is_empty()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert!(BitmapArch::from(0b100).any());
+    /// assert!(!BitmapArch::default().any());
+    /// ```
+    pub fn any(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Returns true if every bit is set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert!(BitmapArch::new(true).is_full());
+    /// assert!(!BitmapArch::from(0b100).is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.0 == usize::MAX
+    }
+
+    /// Finds the index of the lowest set bit, or `None` if no bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(BitmapArch::from(0b10100).find_first_set(), Some(2));
+    /// assert_eq!(BitmapArch::default().find_first_set(), None);
+    /// ```
+    pub fn find_first_set(&self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.0.trailing_zeros() as usize)
+        }
+    }
+
+    /// Finds the index of the highest set bit, or `None` if no bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(BitmapArch::from(0b10100).find_last_set(), Some(4));
+    /// assert_eq!(BitmapArch::default().find_last_set(), None);
+    /// ```
+    pub fn find_last_set(&self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(BitmapArch::MAP_LENGTH - 1 - self.0.leading_zeros() as usize)
+        }
+    }
+
+    /// Finds the index of the lowest cleared bit, or `None` if every bit is set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(BitmapArch::from(0b10111).find_first_clear(), Some(3));
+    /// assert_eq!(BitmapArch::new(true).find_first_clear(), None);
+    /// ```
+    pub fn find_first_clear(&self) -> Option<usize> {
+        (!*self).find_first_set()
+    }
+
+    /// Finds the index of the highest cleared bit, or `None` if every bit is set.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::new(true) >> 1;
+    /// assert_eq!(bitmap.find_last_clear(), Some(BitmapArch::capacity() - 1));
+    /// assert_eq!(BitmapArch::new(true).find_last_clear(), None);
+    /// ```
+    pub fn find_last_clear(&self) -> Option<usize> {
+        (!*self).find_last_set()
+    }
+
+    /// Finds the index of the lowest set bit strictly after the index `after`, or `None` if
+    /// there are no set bits beyond that point. Out-of-bounds values of `after` return `None`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let bitmap = BitmapArch::from(0b100101);
+    /// assert_eq!(bitmap.find_next_set(0), Some(2));
+    /// assert_eq!(bitmap.find_next_set(2), Some(5));
+    /// assert_eq!(bitmap.find_next_set(5), None);
+    /// ```
+    pub fn find_next_set(&self, after: usize) -> Option<usize> {
+        if after >= BitmapArch::MAP_LENGTH - 1 {
+            return None;
+        }
+
+        BitmapArch(self.0 & (usize::MAX << (after + 1))).find_first_set()
+    }
+
+    /// Returns the full-width binary representation of the bitmap, as shown by `Display`, with
+    /// an underscore between every `group_size` bits counting from the most significant bit. A
+    /// `group_size` of 0 leaves the string ungrouped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let grouped = BitmapArch::from(0xA5).to_binary_string_grouped(4);
+    /// assert!(grouped.ends_with("1010_0101"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_binary_string_grouped(&self, group_size: usize) -> String {
+        let binary = self.to_string();
+        if group_size == 0 {
+            return binary;
+        }
+
+        let mut grouped = String::with_capacity(binary.len() + binary.len() / group_size);
+        for (i, digit) in binary.chars().enumerate() {
+            if i > 0 && i % group_size == 0 {
+                grouped.push('_');
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Adds `rhs` to the bitmap's value, wrapping around at the boundary of the type rather
+    /// than panicking on overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(*BitmapArch::new(true).wrapping_add(2), 1);
+    /// ```
+    pub fn wrapping_add(self, rhs: usize) -> BitmapArch {
+        BitmapArch(self.0.wrapping_add(rhs))
+    }
+
+    /// Subtracts `rhs` from the bitmap's value, wrapping around at the boundary of the type
+    /// rather than panicking on underflow.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::BitmapArch;
     ///
-    /// let a = BitmapArch::new(true);
-    /// assert_eq!(*a, usize::MAX);
-    ///
-    /// let b = BitmapArch::new(false);
-    /// assert_eq!(*b, 0);
+    /// assert_eq!(BitmapArch::from(1).wrapping_sub(2), BitmapArch::new(true));
     /// ```
-    pub fn new(value: bool) -> BitmapArch {
-        BitmapArch(if value { usize::MAX } else { 0 })
+    pub fn wrapping_sub(self, rhs: usize) -> BitmapArch {
+        BitmapArch(self.0.wrapping_sub(rhs))
     }
 
-    /// Create a new bitmap that has its bits set from `begin` (inclusive) to `end` (exclusive).
-    /// If begin is greater than the map length or end is 0, will return a bitmap with all bits set to
-    /// the opposite of value.
+    /// Multiplies the bitmap's value by `rhs`, wrapping around at the boundary of the type
+    /// rather than panicking on overflow.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::BitmapArch;
     ///
-    /// let a = BitmapArch::create_bit_mask(3, 7, true);
-    /// assert_eq!(*a, 0b1111000);
-    ///
-    /// let b = BitmapArch::create_bit_mask(3, 6, false); // Results in 1..1000111
-    /// assert_eq!(b, BitmapArch::new(true) ^ 0b111000);
+    /// assert_eq!(BitmapArch::new(true).wrapping_mul(2), BitmapArch::new(true) - 1);
     /// ```
-    pub fn create_bit_mask(begin: usize, end: usize, value: bool) -> BitmapArch {
-        if value {
-            if begin >= BitmapArch::MAP_LENGTH || end < 1 {
-                BitmapArch(0)
-            } else if end >= BitmapArch::MAP_LENGTH {
-                BitmapArch(usize::MAX << begin)
-            } else {
-                BitmapArch(usize::MAX << begin & usize::MAX >> BitmapArch::MAP_LENGTH - end)
-            }
-        } else {
-            !BitmapArch::create_bit_mask(begin, end, true)
-        }
+    pub fn wrapping_mul(self, rhs: usize) -> BitmapArch {
+        BitmapArch(self.0.wrapping_mul(rhs))
     }
 
-    /// Creates a new, empty `BitmapArch`, and sets the desired index before returning.
+    /// Adds `rhs` to the bitmap's value, returning `None` on overflow.
+    ///
+    /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::BitmapArch;
     ///
-    /// let a = BitmapArch::from_set(5).unwrap();
+    /// assert_eq!(BitmapArch::from(1).checked_add(2), Some(BitmapArch::from(3)));
+    /// assert_eq!(BitmapArch::new(true).checked_add(1), None);
+    /// ```
+    pub fn checked_add(self, rhs: usize) -> Option<BitmapArch> {
+        self.0.checked_add(rhs).map(BitmapArch)
+    }
+
+    /// Subtracts `rhs` from the bitmap's value, returning `None` on underflow.
     ///
-    /// // The above is equivalent to:
+    /// ## Example
     ///
-    /// let mut b = BitmapArch::from(0);
-    /// b.set(5, true);
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
     ///
-    /// assert_eq!(a, b);
+    /// assert_eq!(BitmapArch::from(3).checked_sub(2), Some(BitmapArch::from(1)));
+    /// assert_eq!(BitmapArch::from(1).checked_sub(2), None);
     /// ```
-    pub fn from_set(index: usize) -> Option<BitmapArch> {
-        if index >= BitmapArch::MAP_LENGTH {
-            return None;
-        }
-
-        let mut bitmap = BitmapArch::default();
-        bitmap.set(index, true).unwrap();
-        Some(bitmap)
+    pub fn checked_sub(self, rhs: usize) -> Option<BitmapArch> {
+        self.0.checked_sub(rhs).map(BitmapArch)
     }
 
-    /// Sets the desired index, to the value provided. Note that indexing starts
-    /// at 0.
+    /// Multiplies the bitmap's value by `rhs`, returning `None` on overflow.
     ///
-    /// ## Returns
+    /// ## Example
     ///
-    /// Returns a `Result` based on the outcome. If an `Err<String>` was returned,
-    /// it was because an out-of-bounds index was attempted to be set. In that
-    /// case the bitmap's state remains unchanged.
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(BitmapArch::from(3).checked_mul(2), Some(BitmapArch::from(6)));
+    /// assert_eq!(BitmapArch::new(true).checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(self, rhs: usize) -> Option<BitmapArch> {
+        self.0.checked_mul(rhs).map(BitmapArch)
+    }
+
+    /// Divides the bitmap's value by `rhs`, returning `None` if `rhs` is 0.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::BitmapArch;
     ///
-    /// let mut bitmap = BitmapArch::default();
-    /// assert_eq!(*bitmap, 0);
-    ///
-    /// bitmap.set(4, true);
-    /// assert_eq!(*bitmap, 16);
+    /// assert_eq!(BitmapArch::from(6).checked_div(2), Some(BitmapArch::from(3)));
+    /// assert_eq!(BitmapArch::from(6).checked_div(0), None);
     /// ```
-    pub fn set(&mut self, index: usize, value: bool) -> Result<(), String> {
-        if index >= BitmapArch::MAP_LENGTH {
-            return Err(String::from(
-                "Tried to set bit that's out of range of the bitmap (range: ",
-            ) + &BitmapArch::MAP_LENGTH.to_string()
-                + ", index: "
-                + &index.to_string()
-                + ")");
-        }
-
-        if value {
-            let mask = 1 << index;
-            self.0 |= mask;
-        } else {
-            let mask = usize::MAX - (1 << index);
-            self.0 &= mask;
-        }
+    pub fn checked_div(self, rhs: usize) -> Option<BitmapArch> {
+        self.0.checked_div(rhs).map(BitmapArch)
+    }
 
-        Ok(())
+    /// Adds `rhs` to the bitmap's value, stopping at `usize::MAX` rather than overflowing.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(*BitmapArch::from(1).saturating_add(2), 3);
+    /// assert_eq!(BitmapArch::new(true).saturating_add(1), BitmapArch::new(true));
+    /// ```
+    pub fn saturating_add(self, rhs: usize) -> BitmapArch {
+        BitmapArch(self.0.saturating_add(rhs))
     }
 
-    /// Set bits from begin (inclusive) to end (exclusive) to the given value.
+    /// Subtracts `rhs` from the bitmap's value, stopping at 0 rather than underflowing.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::BitmapArch;
     ///
-    /// let mut bitmap = BitmapArch::default();
-    /// assert_eq!(*bitmap, 0);
+    /// assert_eq!(*BitmapArch::from(3).saturating_sub(2), 1);
+    /// assert_eq!(*BitmapArch::from(1).saturating_sub(2), 0);
+    /// ```
+    pub fn saturating_sub(self, rhs: usize) -> BitmapArch {
+        BitmapArch(self.0.saturating_sub(rhs))
+    }
+
+    /// Adds the value of another bitmap to this one's, stopping at `usize::MAX` rather than
+    /// overflowing.
     ///
-    /// bitmap.set_range(2, 7, true);
-    /// assert_eq!(*bitmap, 0b1111100);
+    /// ## Example
     ///
-    /// bitmap.set_range(3, 5, false);
-    /// assert_eq!(*bitmap, 0b1100100);
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// let full = BitmapArch::new(true);
+    /// assert_eq!(full.saturating_add_bitmap(BitmapArch::from(1)), full);
     /// ```
-    pub fn set_range(&mut self, begin: usize, end: usize, value: bool) {
-        if value {
-            *self |= BitmapArch::create_bit_mask(begin, end, true);
-        } else {
-            *self &= BitmapArch::create_bit_mask(begin, end, false);
-        }
+    pub fn saturating_add_bitmap(self, rhs: BitmapArch) -> BitmapArch {
+        self.saturating_add(rhs.0)
     }
 
-    /// Gets the bit at the given index. Note that indexing starts at 0.
+    /// Subtracts the value of another bitmap from this one's, stopping at 0 rather than
+    /// underflowing.
     ///
-    /// ## Returns
+    /// ## Example
     ///
-    /// Returns a `Result` based on the outcome.
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
     ///
-    /// If `Ok<bool>` is returned, then the contained value in ok is the state
-    /// of the given bit
+    /// let a = BitmapArch::from(1);
+    /// assert_eq!(a.saturating_sub_bitmap(BitmapArch::from(2)), BitmapArch::default());
+    /// ```
+    pub fn saturating_sub_bitmap(self, rhs: BitmapArch) -> BitmapArch {
+        self.saturating_sub(rhs.0)
+    }
+
+    /// Adds `rhs` to the bitmap's value, returning the wrapped result along with a flag
+    /// indicating whether an overflow occurred.
     ///
-    /// If an `Err<String>` was returned, it was because you tried to get
-    /// an out-of-bounds index.
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(BitmapArch::from(1).overflowing_add(2), (BitmapArch::from(3), false));
+    /// assert_eq!(BitmapArch::new(true).overflowing_add(1), (BitmapArch::default(), true));
+    /// ```
+    pub fn overflowing_add(self, rhs: usize) -> (BitmapArch, bool) {
+        let (value, overflowed) = self.0.overflowing_add(rhs);
+        (BitmapArch(value), overflowed)
+    }
+
+    /// Subtracts `rhs` from the bitmap's value, returning the wrapped result along with a flag
+    /// indicating whether an underflow occurred.
     ///
     /// ## Example
     ///
     /// ```rust
     /// use fixed_bitmaps::BitmapArch;
     ///
-    /// let bitmap = BitmapArch::from(0b1010);
-    /// assert_eq!(bitmap.get(2).unwrap(), false);
-    /// assert_eq!(bitmap.get(3).unwrap(), true);
+    /// assert_eq!(BitmapArch::from(3).overflowing_sub(2), (BitmapArch::from(1), false));
+    /// assert_eq!(BitmapArch::default().overflowing_sub(1), (BitmapArch::new(true), true));
     /// ```
-    pub fn get(&self, index: usize) -> Result<bool, String> {
-        if index >= BitmapArch::MAP_LENGTH {
-            return Err(String::from(
-                "Tried to get bit that's out of range of the bitmap (range: ",
-            ) + &BitmapArch::MAP_LENGTH.to_string()
-                + ", index: "
-                + &index.to_string()
-                + ")");
-        }
+    pub fn overflowing_sub(self, rhs: usize) -> (BitmapArch, bool) {
+        let (value, overflowed) = self.0.overflowing_sub(rhs);
+        (BitmapArch(value), overflowed)
+    }
 
-        let mask = 1 << index;
-        Ok(self.0 & mask > 0)
+    /// Multiplies the bitmap's value by `rhs`, returning the wrapped result along with a flag
+    /// indicating whether an overflow occurred.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::BitmapArch;
+    ///
+    /// assert_eq!(BitmapArch::from(3).overflowing_mul(2), (BitmapArch::from(6), false));
+    /// assert!(BitmapArch::new(true).overflowing_mul(2).1);
+    /// ```
+    pub fn overflowing_mul(self, rhs: usize) -> (BitmapArch, bool) {
+        let (value, overflowed) = self.0.overflowing_mul(rhs);
+        (BitmapArch(value), overflowed)
     }
 }
 
+/// Shows the bitmap in binary, padded with leading zeros to the full width of the bitmap so that
+/// every bit's position is unambiguous.
 impl Display for BitmapArch {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{:b}", self.0)
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{:0width$b}", self.0, width = BitmapArch::MAP_LENGTH)
     }
 }
 
 impl Debug for BitmapArch {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "BitmapArch({:X})", self.0)
     }
 }
 
+/// Formats the bitmap's value exactly as `usize` does, so flags such as `#` and a width like
+/// `{:08b}` behave as expected.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::BitmapArch;
+///
+/// let bitmap = BitmapArch::from(0b101);
+/// assert_eq!(format!("{:b}", bitmap), "101");
+/// assert_eq!(format!("{:#06b}", bitmap), "0b0101");
+/// ```
+impl Binary for BitmapArch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Binary::fmt(&self.0, f)
+    }
+}
+
 impl From<usize> for BitmapArch {
     fn from(value: usize) -> Self {
         BitmapArch(value)
     }
 }
 
+/// Parses a string of `'0'` and `'1'` characters, with the most significant bit first. Strings
+/// shorter than the bitmap's capacity are padded with leading zeros.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::BitmapArch;
+///
+/// let bitmap: BitmapArch = "1011".parse().unwrap();
+/// assert_eq!(*bitmap, 0b1011);
+///
+/// assert!("1021".parse::<BitmapArch>().is_err());
+/// ```
+impl FromStr for BitmapArch {
+    type Err = ParseBitmapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseBitmapError::Empty);
+        }
+
+        if s.len() > BitmapArch::MAP_LENGTH {
+            return Err(ParseBitmapError::TooLong {
+                length: s.len(),
+                capacity: BitmapArch::MAP_LENGTH,
+            });
+        }
+
+        let mut value = 0;
+        for (index, character) in s.char_indices() {
+            let bit = match character {
+                '0' => 0,
+                '1' => 1,
+                _ => return Err(ParseBitmapError::InvalidDigit { character, index }),
+            };
+            value = (value << 1) | bit;
+        }
+
+        Ok(BitmapArch(value))
+    }
+}
+
+/// Interprets the bytes as the bitmap's value in little-endian byte order, the same as
+/// `BitmapArch::from_le_bytes`.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::BitmapArch;
+///
+/// let mut bytes = BitmapArch::default().to_le_bytes();
+/// bytes[0] = 0b101;
+/// assert_eq!(*BitmapArch::from(bytes), 0b101);
+/// ```
+impl From<[u8; mem::size_of::<usize>()]> for BitmapArch {
+    fn from(bytes: [u8; mem::size_of::<usize>()]) -> Self {
+        BitmapArch::from_le_bytes(bytes)
+    }
+}
+
+/// Produces the bitmap's value in little-endian byte order, the same as
+/// `BitmapArch::to_le_bytes`.
+impl From<BitmapArch> for [u8; mem::size_of::<usize>()] {
+    fn from(bitmap: BitmapArch) -> Self {
+        bitmap.to_le_bytes()
+    }
+}
+
+/// Collects bits starting from index 0. Any bits beyond the map length are ignored.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::BitmapArch;
+///
+/// let bitmap: BitmapArch = vec![true, false, true, true].into_iter().collect();
+/// assert_eq!(*bitmap, 0b1101);
+/// ```
+impl FromIterator<bool> for BitmapArch {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bitmap = BitmapArch::default();
+
+        for (index, bit) in iter.into_iter().take(BitmapArch::MAP_LENGTH).enumerate() {
+            if bit {
+                bitmap.0 |= 1 << index;
+            }
+        }
+
+        bitmap
+    }
+}
+
 impl BitmapSize for BitmapArch {
     const MAP_LENGTH: usize = mem::size_of::<usize>() * 8;
 }
 
+impl BitmapOps for BitmapArch {
+    fn get(&self, index: usize) -> Result<bool, BitmapError> {
+        BitmapArch::get(self, index)
+    }
+
+    fn set(&mut self, index: usize, value: bool) -> Result<(), BitmapError> {
+        BitmapArch::set(self, index, value)
+    }
+
+    fn count_ones(&self) -> usize {
+        BitmapArch::count_ones(self) as usize
+    }
+
+    fn is_empty(&self) -> bool {
+        BitmapArch::is_empty(self)
+    }
+
+    fn is_full(&self) -> bool {
+        BitmapArch::is_full(self)
+    }
+}
+
 // Traits implementing bitwise operations between Bitmaps of the same type
 
 impl BitAnd for BitmapArch {
@@ -500,3 +1974,127 @@ impl Deref for BitmapArch {
         &self.0
     }
 }
+
+// Indexing
+
+/// Gets the bit at the given index, panicking if it's out of bounds as slice indexing does. Use
+/// `get()` to handle an out-of-bounds index instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::BitmapArch;
+///
+/// let bitmap = BitmapArch::from(0b1010);
+/// assert!(bitmap[3]);
+/// assert!(!bitmap[2]);
+/// ```
+impl Index<usize> for BitmapArch {
+    type Output = bool;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if self.get(index).expect("bitmap index out of bounds") {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+// Iterators
+
+/// An iterator over the indices of the set bits of a `BitmapArch`, in ascending order.
+///
+/// Created by `BitmapArch::set_bits()`.
+#[derive(Clone, Debug)]
+pub struct BitmapArchSetBits(usize);
+
+impl Iterator for BitmapArchSetBits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let index = self.0.trailing_zeros() as usize;
+        // Clears the lowest set bit
+        self.0 &= self.0 - 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.0.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitmapArchSetBits {}
+
+/// An iterator over the indices of the cleared bits of a `BitmapArch`, in ascending order.
+///
+/// Created by `BitmapArch::clear_bits()`.
+#[derive(Clone, Debug)]
+pub struct BitmapArchClearBits(BitmapArchSetBits);
+
+impl Iterator for BitmapArchClearBits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for BitmapArchClearBits {}
+
+/// An iterator over every bit of a `BitmapArch` as a `bool`, starting from index 0.
+///
+/// Created by `BitmapArch::bits()`.
+#[derive(Clone, Debug)]
+pub struct BitmapArchBits {
+    bitmap: usize,
+    index: usize,
+}
+
+impl Iterator for BitmapArchBits {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= BitmapArch::MAP_LENGTH {
+            return None;
+        }
+
+        let bit = (self.bitmap >> self.index) & 1 == 1;
+        self.index += 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = BitmapArch::MAP_LENGTH - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitmapArchBits {}
+
+impl IntoIterator for BitmapArch {
+    type Item = bool;
+    type IntoIter = BitmapArchBits;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits()
+    }
+}
+
+impl IntoIterator for &BitmapArch {
+    type Item = bool;
+    type IntoIter = BitmapArchBits;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bits()
+    }
+}
@@ -0,0 +1,139 @@
+//! Implementations between different sizes of primitive bitmaps. These can't be generated from
+//! `bitmap128.rs` like the rest of the primitive modules, as they depend on how two sizes relate
+//! to each other, so they're written out once here with macros instead.
+//!
+//! `BitmapArch` is left out, as the size of `usize` depends on the target platform.
+
+use super::{Bitmap128, Bitmap16, Bitmap32, Bitmap64, Bitmap8, BitmapSize};
+use crate::TryFromBitmapError;
+use core::convert::TryFrom;
+
+/// Compares a smaller bitmap with a larger one by widening the smaller bitmap's value. The two
+/// bitmaps are only equal if the larger one has none of its upper bits set.
+macro_rules! impl_widening_eq {
+    ($small:ident, $large:ident, $large_int:ty) => {
+        impl PartialEq<$large> for $small {
+            fn eq(&self, other: &$large) -> bool {
+                <$large_int>::from(**self) == **other
+            }
+        }
+
+        impl PartialEq<$small> for $large {
+            fn eq(&self, other: &$small) -> bool {
+                other == self
+            }
+        }
+    };
+}
+
+impl_widening_eq!(Bitmap8, Bitmap16, u16);
+impl_widening_eq!(Bitmap8, Bitmap32, u32);
+impl_widening_eq!(Bitmap8, Bitmap64, u64);
+impl_widening_eq!(Bitmap8, Bitmap128, u128);
+impl_widening_eq!(Bitmap16, Bitmap32, u32);
+impl_widening_eq!(Bitmap16, Bitmap64, u64);
+impl_widening_eq!(Bitmap16, Bitmap128, u128);
+impl_widening_eq!(Bitmap32, Bitmap64, u64);
+impl_widening_eq!(Bitmap32, Bitmap128, u128);
+impl_widening_eq!(Bitmap64, Bitmap128, u128);
+
+/// Narrows a larger bitmap into a smaller one, failing if any of the bits that don't fit in the
+/// smaller bitmap are set.
+macro_rules! impl_narrowing_try_from {
+    ($large:ident, $small:ident, $small_int:ty) => {
+        impl TryFrom<$large> for $small {
+            type Error = TryFromBitmapError;
+
+            fn try_from(bitmap: $large) -> Result<Self, Self::Error> {
+                <$small_int>::try_from(*bitmap)
+                    .map($small::from)
+                    .map_err(|_| TryFromBitmapError {
+                        from_length: $large::MAP_LENGTH,
+                        to_length: $small::MAP_LENGTH,
+                    })
+            }
+        }
+    };
+}
+
+impl_narrowing_try_from!(Bitmap16, Bitmap8, u8);
+impl_narrowing_try_from!(Bitmap32, Bitmap8, u8);
+impl_narrowing_try_from!(Bitmap64, Bitmap8, u8);
+impl_narrowing_try_from!(Bitmap128, Bitmap8, u8);
+impl_narrowing_try_from!(Bitmap32, Bitmap16, u16);
+impl_narrowing_try_from!(Bitmap64, Bitmap16, u16);
+impl_narrowing_try_from!(Bitmap128, Bitmap16, u16);
+impl_narrowing_try_from!(Bitmap64, Bitmap32, u32);
+impl_narrowing_try_from!(Bitmap128, Bitmap32, u32);
+impl_narrowing_try_from!(Bitmap128, Bitmap64, u64);
+
+/// Widens a smaller bitmap into a larger one. The value is zero-extended, so this can never fail.
+macro_rules! impl_widening_from {
+    ($small:ident, $large:ident, $large_int:ty) => {
+        impl From<$small> for $large {
+            fn from(bitmap: $small) -> Self {
+                $large::from(<$large_int>::from(*bitmap))
+            }
+        }
+    };
+}
+
+impl_widening_from!(Bitmap8, Bitmap16, u16);
+impl_widening_from!(Bitmap8, Bitmap32, u32);
+impl_widening_from!(Bitmap8, Bitmap64, u64);
+impl_widening_from!(Bitmap8, Bitmap128, u128);
+impl_widening_from!(Bitmap16, Bitmap32, u32);
+impl_widening_from!(Bitmap16, Bitmap64, u64);
+impl_widening_from!(Bitmap16, Bitmap128, u128);
+impl_widening_from!(Bitmap32, Bitmap64, u64);
+impl_widening_from!(Bitmap32, Bitmap128, u128);
+impl_widening_from!(Bitmap64, Bitmap128, u128);
+
+/// Splits a bitmap into two bitmaps of half the size, and joins two halves back together, either
+/// side by side or with their bits interleaved.
+macro_rules! impl_halves {
+    ($whole:ident, $whole_int:ty, $half:ident, $half_int:ty) => {
+        impl $whole {
+            /// Splits the bitmap into its low and high halves, returned as `(low, high)`.
+            pub fn split(self) -> ($half, $half) {
+                let low = *self as $half_int;
+                let high = (*self >> $half::MAP_LENGTH) as $half_int;
+                ($half::from(low), $half::from(high))
+            }
+
+            /// Joins two halves into one bitmap, with `low` in the lower bits and `high` in the upper bits.
+            ///
+            /// This is the inverse of `split`.
+            pub fn concat(low: $half, high: $half) -> Self {
+                let low = *low as $whole_int;
+                let high = (*high as $whole_int) << $half::MAP_LENGTH;
+                Self::from(low | high)
+            }
+
+            /// Interleaves the bits of two halves, so that bit `i` of `low` becomes bit `2 * i`
+            /// and bit `i` of `high` becomes bit `2 * i + 1`. This is the Morton, or Z-order,
+            /// encoding of the two halves.
+            pub fn interleave(low: $half, high: $half) -> Self {
+                let low = Self::pdep(Self::from_alternating(true), Self::from(*low as $whole_int));
+                let high = Self::pdep(
+                    Self::from_alternating(false),
+                    Self::from(*high as $whole_int),
+                );
+                low | high
+            }
+
+            /// Separates the bits at even and odd indices into two halves, returned as
+            /// `(even, odd)`. This is the inverse of `interleave`.
+            pub fn deinterleave(self) -> ($half, $half) {
+                let even = *self.pext(Self::from_alternating(true)) as $half_int;
+                let odd = *self.pext(Self::from_alternating(false)) as $half_int;
+                ($half::from(even), $half::from(odd))
+            }
+        }
+    };
+}
+
+impl_halves!(Bitmap16, u16, Bitmap8, u8);
+impl_halves!(Bitmap32, u32, Bitmap16, u16);
+impl_halves!(Bitmap64, u64, Bitmap32, u32);
+impl_halves!(Bitmap128, u128, Bitmap64, u64);
@@ -4,14 +4,56 @@ mod bitmap32;
 mod bitmap64;
 mod bitmap8;
 mod bitmap_arch;
+mod conversions;
+mod nibbles;
+
+use crate::BitmapError;
 
 pub trait BitmapSize {
     const MAP_LENGTH: usize;
 }
 
-pub use bitmap128::Bitmap128;
-pub use bitmap16::Bitmap16;
-pub use bitmap32::Bitmap32;
-pub use bitmap64::Bitmap64;
-pub use bitmap8::Bitmap8;
-pub use bitmap_arch::BitmapArch;
+/// The operations shared by every bitmap, primitive and oversized alike, so that algorithms can
+/// be written generically over any bitmap type.
+///
+/// ## Example
+///
+/// ```rust
+/// use fixed_bitmaps::{Bitmap64, BitmapKB, BitmapOps};
+///
+/// fn first_free<T: BitmapOps>(bitmap: &T) -> Option<usize> {
+///     (0..T::capacity()).find(|&i| !bitmap.get(i).unwrap())
+/// }
+///
+/// assert_eq!(first_free(&Bitmap64::from(0b0111)), Some(3));
+/// assert_eq!(first_free(&BitmapKB::new(true)), None);
+/// ```
+pub trait BitmapOps: BitmapSize {
+    /// Gets the bit at the given index, returning an `Err<BitmapError>` if it's out of bounds.
+    fn get(&self, index: usize) -> Result<bool, BitmapError>;
+
+    /// Sets the bit at the given index to the value provided, returning an `Err<BitmapError>`
+    /// if it's out of bounds.
+    fn set(&mut self, index: usize, value: bool) -> Result<(), BitmapError>;
+
+    /// The number of bits the bitmap holds.
+    fn capacity() -> usize {
+        Self::MAP_LENGTH
+    }
+
+    /// The number of bits that are set.
+    fn count_ones(&self) -> usize;
+
+    /// Whether no bits are set.
+    fn is_empty(&self) -> bool;
+
+    /// Whether every bit is set.
+    fn is_full(&self) -> bool;
+}
+
+pub use bitmap128::{Bitmap128, Bitmap128Bits, Bitmap128ClearBits, Bitmap128SetBits};
+pub use bitmap16::{Bitmap16, Bitmap16Bits, Bitmap16ClearBits, Bitmap16SetBits};
+pub use bitmap32::{Bitmap32, Bitmap32Bits, Bitmap32ClearBits, Bitmap32SetBits};
+pub use bitmap64::{Bitmap64, Bitmap64Bits, Bitmap64ClearBits, Bitmap64SetBits};
+pub use bitmap8::{Bitmap8, Bitmap8Bits, Bitmap8ClearBits, Bitmap8SetBits};
+pub use bitmap_arch::{BitmapArch, BitmapArchBits, BitmapArchClearBits, BitmapArchSetBits};
@@ -0,0 +1,42 @@
+//! Nibble helpers that only make sense for `Bitmap8`. These live outside of the generated
+//! `bitmap8.rs` so that they aren't overwritten when changes to `bitmap128.rs` are propagated.
+
+use super::Bitmap8;
+
+impl Bitmap8 {
+    /// Splits the bitmap into its high and low nibbles, returned in that order. Each
+    /// nibble is in the range `0..=15`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap8;
+    ///
+    /// let bitmap = Bitmap8::from(0xAB);
+    /// assert_eq!(bitmap.to_nibbles(), (0xA, 0xB));
+    /// ```
+    pub fn to_nibbles(&self) -> (u8, u8) {
+        (self.to_u8() >> 4, self.to_u8() & 0xF)
+    }
+
+    /// Creates a new bitmap from a high and a low nibble.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if either `high` or `low` doesn't fit in 4 bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use fixed_bitmaps::Bitmap8;
+    ///
+    /// let bitmap = Bitmap8::from_nibbles(0xA, 0xB);
+    /// assert_eq!(*bitmap, 0xAB);
+    /// ```
+    pub fn from_nibbles(high: u8, low: u8) -> Bitmap8 {
+        assert!(high <= 0xF, "high nibble {:#X} doesn't fit in 4 bits", high);
+        assert!(low <= 0xF, "low nibble {:#X} doesn't fit in 4 bits", low);
+
+        Bitmap8::from(high << 4 | low)
+    }
+}
@@ -0,0 +1,79 @@
+use fixed_bitmaps::{AtomicBitmap8, Bitmap8, BitmapError, BitmapSize};
+use std::sync::atomic::Ordering;
+
+#[test]
+fn default_is_0() {
+    let bitmap = AtomicBitmap8::default();
+    assert_eq!(bitmap.load(Ordering::SeqCst), Bitmap8::default());
+    assert_eq!(AtomicBitmap8::new(true).into_inner(), Bitmap8::new(true));
+}
+
+#[test]
+fn get_set_and_atomic_toggle() {
+    let bitmap = AtomicBitmap8::from(Bitmap8::from(0b1000));
+
+    bitmap.set(0, true, Ordering::SeqCst).unwrap();
+    bitmap.set(3, false, Ordering::SeqCst).unwrap();
+    bitmap.atomic_toggle(5, Ordering::SeqCst).unwrap();
+    assert!(bitmap.get(0, Ordering::SeqCst).unwrap());
+    assert!(!bitmap.get(3, Ordering::SeqCst).unwrap());
+    assert!(bitmap.get(5, Ordering::SeqCst).unwrap());
+    assert_eq!(bitmap.load(Ordering::SeqCst), Bitmap8::from(0b10_0001));
+
+    bitmap.store(Bitmap8::from(0b10), Ordering::SeqCst);
+    assert_eq!(bitmap.into_inner(), Bitmap8::from(0b10));
+}
+
+#[test]
+fn fetch_set_and_clear_return_previous_bit() {
+    let bitmap = AtomicBitmap8::default();
+
+    assert_eq!(bitmap.fetch_set(2, Ordering::SeqCst), Ok(false));
+    assert_eq!(bitmap.fetch_set(2, Ordering::SeqCst), Ok(true));
+    assert_eq!(bitmap.fetch_clear(2, Ordering::SeqCst), Ok(true));
+    assert_eq!(bitmap.fetch_clear(2, Ordering::SeqCst), Ok(false));
+    assert!(bitmap
+        .fetch_set(Bitmap8::MAP_LENGTH, Ordering::SeqCst)
+        .is_err());
+    assert!(bitmap
+        .fetch_clear(Bitmap8::MAP_LENGTH, Ordering::SeqCst)
+        .is_err());
+    assert_eq!(bitmap.into_inner(), Bitmap8::default());
+}
+
+#[test]
+fn out_of_bounds_is_an_error() {
+    let bitmap = AtomicBitmap8::default();
+    let error = BitmapError::IndexOutOfBounds {
+        index: Bitmap8::MAP_LENGTH,
+        capacity: Bitmap8::MAP_LENGTH,
+    };
+
+    assert_eq!(
+        bitmap.get(Bitmap8::MAP_LENGTH, Ordering::SeqCst),
+        Err(error)
+    );
+    assert_eq!(
+        bitmap.set(Bitmap8::MAP_LENGTH, true, Ordering::SeqCst),
+        Err(error)
+    );
+    assert_eq!(
+        bitmap.atomic_toggle(Bitmap8::MAP_LENGTH, Ordering::SeqCst),
+        Err(error)
+    );
+    assert_eq!(bitmap.load(Ordering::SeqCst), Bitmap8::default());
+}
+
+#[test]
+fn set_from_many_threads() {
+    let bitmap = AtomicBitmap8::default();
+
+    std::thread::scope(|scope| {
+        for index in 0..Bitmap8::MAP_LENGTH {
+            let bitmap = &bitmap;
+            scope.spawn(move || bitmap.set(index, true, Ordering::Relaxed).unwrap());
+        }
+    });
+
+    assert_eq!(bitmap.into_inner(), Bitmap8::new(true));
+}
@@ -0,0 +1,8 @@
+#[cfg(all(feature = "atomic", target_has_atomic = "16"))]
+mod bitmap16;
+#[cfg(all(feature = "atomic", target_has_atomic = "32"))]
+mod bitmap32;
+#[cfg(all(feature = "atomic", target_has_atomic = "64"))]
+mod bitmap64;
+#[cfg(all(feature = "atomic", target_has_atomic = "8"))]
+mod bitmap8;
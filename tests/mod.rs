@@ -1,2 +1,3 @@
+mod atomic;
 mod oversized;
 mod primitives;
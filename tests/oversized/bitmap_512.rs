@@ -1,9 +1,15 @@
-use std::{convert::TryInto, mem::size_of};
+use std::{
+    convert::{TryFrom, TryInto},
+    mem::size_of,
+};
 
-use fixed_bitmaps::{Bitmap512, BitmapSize};
+use fixed_bitmaps::{BitmapError, Bitmap512, BitmapOps, BitmapSize};
+use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Token};
 
 const SIZE_USIZE: usize = size_of::<usize>() * 8;
 const NUM_ELEMENTS: usize = Bitmap512::MAP_LENGTH / SIZE_USIZE;
+/// Human-readable formats only use hex strings from `Bitmap1024` upwards.
+const USES_HEX: bool = Bitmap512::MAP_LENGTH >= 1_024;
 
 #[test]
 fn default_is_0() {
@@ -28,14 +34,16 @@ fn copy_test() {
 
 #[test]
 fn equality_test() {
+    let high = Bitmap512::MAP_LENGTH - 2;
+    let low = Bitmap512::MAP_LENGTH / 2;
     let mut a = Bitmap512::default();
-    a.set(1054, true).unwrap();
-    a.set(1000, true).unwrap();
+    a.set(high, true).unwrap();
+    a.set(low, true).unwrap();
     let mut b = Bitmap512::default();
-    b.set(1054, true).unwrap();
-    b.set(1000, true).unwrap();
+    b.set(high, true).unwrap();
+    b.set(low, true).unwrap();
     let mut c = Bitmap512::default();
-    c.set(1054, true).unwrap();
+    c.set(high, true).unwrap();
     assert_eq!(a, b);
     assert_ne!(a, c);
 }
@@ -214,3 +222,927 @@ fn and_functionality() {
 //     let value = *bitmap;
 //     assert_eq!(value, 17);
 // }
+
+#[test]
+fn count_ones_counts_set_bits() {
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(bitmap.count_ones(), 0);
+
+    bitmap.set(0, true).unwrap();
+    bitmap.set(SIZE_USIZE, true).unwrap();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+    assert_eq!(bitmap.count_ones(), 3);
+
+    assert_eq!(Bitmap512::new(true).count_ones(), Bitmap512::MAP_LENGTH);
+}
+
+#[test]
+fn leading_and_trailing_zeros() {
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(bitmap.leading_zeros(), Bitmap512::MAP_LENGTH);
+    assert_eq!(bitmap.trailing_zeros(), Bitmap512::MAP_LENGTH);
+
+    bitmap.set(SIZE_USIZE + 3, true).unwrap();
+    assert_eq!(
+        bitmap.leading_zeros(),
+        Bitmap512::MAP_LENGTH - SIZE_USIZE - 4
+    );
+    assert_eq!(bitmap.trailing_zeros(), SIZE_USIZE + 3);
+
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+    bitmap.set(0, true).unwrap();
+    assert_eq!(bitmap.leading_zeros(), 0);
+    assert_eq!(bitmap.trailing_zeros(), 0);
+}
+
+#[test]
+fn set_bits_iterates_ascending() {
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(bitmap.set_bits().next(), None);
+
+    let indices = [0, 5, SIZE_USIZE - 1, SIZE_USIZE, Bitmap512::MAP_LENGTH - 1];
+    for &index in indices.iter() {
+        bitmap.set(index, true).unwrap();
+    }
+
+    let set_bits = bitmap.set_bits();
+    assert_eq!(set_bits.len(), indices.len());
+    assert_eq!(set_bits.collect::<Vec<usize>>(), indices.to_vec());
+    assert_eq!(Bitmap512::new(true).set_bits().count(), Bitmap512::MAP_LENGTH);
+}
+
+#[test]
+fn count_zeros_counts_cleared_bits() {
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(bitmap.count_zeros(), Bitmap512::MAP_LENGTH);
+
+    bitmap.set(3, true).unwrap();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+    assert_eq!(bitmap.count_zeros(), Bitmap512::MAP_LENGTH - 2);
+
+    assert_eq!(Bitmap512::new(true).count_zeros(), 0);
+}
+
+#[test]
+fn is_empty_and_any() {
+    let empty = Bitmap512::default();
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    assert!(empty.is_empty());
+    assert!(!empty.any());
+    assert!(!bitmap.is_empty());
+    assert!(bitmap.any());
+    assert!(Bitmap512::from_set(0).unwrap().any());
+}
+
+#[test]
+fn is_full() {
+    let mut bitmap = Bitmap512::new(true);
+    assert!(bitmap.is_full());
+
+    bitmap.set(0, false).unwrap();
+    assert!(!bitmap.is_full());
+    assert!(!Bitmap512::default().is_full());
+}
+
+#[test]
+fn find_first_set() {
+    let last = Bitmap512::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(bitmap.find_first_set(), None);
+
+    bitmap.set(last, true).unwrap();
+    assert_eq!(bitmap.find_first_set(), Some(last));
+
+    bitmap.set(SIZE_USIZE + 3, true).unwrap();
+    assert_eq!(bitmap.find_first_set(), Some(SIZE_USIZE + 3));
+
+    bitmap.set(5, true).unwrap();
+    assert_eq!(bitmap.find_first_set(), Some(5));
+}
+
+#[test]
+fn find_last_set() {
+    let last = Bitmap512::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(bitmap.find_last_set(), None);
+
+    bitmap.set(0, true).unwrap();
+    assert_eq!(bitmap.find_last_set(), Some(0));
+
+    bitmap.set(SIZE_USIZE + 3, true).unwrap();
+    assert_eq!(bitmap.find_last_set(), Some(SIZE_USIZE + 3));
+
+    bitmap.set(last, true).unwrap();
+    assert_eq!(bitmap.find_last_set(), Some(last));
+}
+
+#[test]
+fn find_first_and_last_clear() {
+    let last = Bitmap512::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap512::new(true);
+    assert_eq!(bitmap.find_first_clear(), None);
+    assert_eq!(bitmap.find_last_clear(), None);
+
+    bitmap.set(SIZE_USIZE + 3, false).unwrap();
+    assert_eq!(bitmap.find_first_clear(), Some(SIZE_USIZE + 3));
+    assert_eq!(bitmap.find_last_clear(), Some(SIZE_USIZE + 3));
+
+    bitmap.set(2, false).unwrap();
+    bitmap.set(last, false).unwrap();
+    assert_eq!(bitmap.find_first_clear(), Some(2));
+    assert_eq!(bitmap.find_last_clear(), Some(last));
+
+    assert_eq!(Bitmap512::default().find_first_clear(), Some(0));
+    assert_eq!(Bitmap512::default().find_last_clear(), Some(last));
+}
+
+#[test]
+fn find_next_set() {
+    let last = Bitmap512::MAP_LENGTH - 1;
+    let middle = (NUM_ELEMENTS - 1) * SIZE_USIZE + 7;
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(bitmap.find_next_set(0), None);
+
+    bitmap.set(0, true).unwrap();
+    bitmap.set(3, true).unwrap();
+    bitmap.set(SIZE_USIZE, true).unwrap();
+    bitmap.set(middle, true).unwrap();
+    bitmap.set(last, true).unwrap();
+
+    assert_eq!(bitmap.find_next_set(0), Some(3));
+    assert_eq!(bitmap.find_next_set(3), Some(SIZE_USIZE));
+    assert_eq!(bitmap.find_next_set(SIZE_USIZE - 1), Some(SIZE_USIZE));
+    assert_eq!(bitmap.find_next_set(SIZE_USIZE), Some(middle));
+    assert_eq!(bitmap.find_next_set(middle), Some(last));
+    assert_eq!(bitmap.find_next_set(last), None);
+    assert_eq!(bitmap.find_next_set(usize::MAX), None);
+}
+
+#[test]
+fn toggle_flips_single_bit() {
+    let last = Bitmap512::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap512::default();
+
+    bitmap.toggle(SIZE_USIZE + 1).unwrap();
+    assert_eq!(bitmap.get(SIZE_USIZE + 1), Ok(true));
+    bitmap.toggle(last).unwrap();
+    assert_eq!(bitmap.get(last), Ok(true));
+    bitmap.toggle(SIZE_USIZE + 1).unwrap();
+    assert_eq!(bitmap.get(SIZE_USIZE + 1), Ok(false));
+    assert_eq!(bitmap.count_ones(), 1);
+
+    assert!(bitmap.toggle(Bitmap512::MAP_LENGTH).is_err());
+    assert_eq!(bitmap.count_ones(), 1);
+}
+
+#[test]
+fn toggle_range_flips_bits() {
+    let length = Bitmap512::MAP_LENGTH;
+    let mut bitmap = Bitmap512::default();
+
+    bitmap.toggle_range(3, 3 * SIZE_USIZE + 5);
+    for i in 0..length {
+        assert_eq!(bitmap.get(i), Ok((3..3 * SIZE_USIZE + 5).contains(&i)));
+    }
+
+    bitmap.toggle_range(0, length);
+    assert_eq!(bitmap.count_ones(), length - (3 * SIZE_USIZE + 2));
+
+    let mut bitmap = Bitmap512::default();
+    bitmap.toggle_range(SIZE_USIZE + 1, SIZE_USIZE + 2);
+    assert_eq!(bitmap, Bitmap512::from_set(SIZE_USIZE + 1).unwrap());
+
+    bitmap.toggle_range(length - 1, length + 5);
+    assert_eq!(bitmap.get(length - 1), Ok(true));
+    assert_eq!(bitmap.count_ones(), 2);
+
+    bitmap.toggle_range(length, length + 5);
+    bitmap.toggle_range(5, 5);
+    assert_eq!(bitmap.count_ones(), 2);
+}
+
+#[test]
+fn reverse_bits() {
+    let last = Bitmap512::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(0, true).unwrap();
+    bitmap.set(3, true).unwrap();
+    bitmap.set(SIZE_USIZE + 7, true).unwrap();
+    let reversed = bitmap.reverse_bits();
+
+    for i in 0..Bitmap512::MAP_LENGTH {
+        assert_eq!(reversed.get(last - i), bitmap.get(i));
+    }
+
+    assert_eq!(reversed.reverse_bits(), bitmap);
+}
+
+#[test]
+fn swap_bits() {
+    let last = Bitmap512::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap512::from_set(2).unwrap();
+
+    bitmap.swap_bits(2, last).unwrap();
+    assert_eq!(bitmap, Bitmap512::from_set(last).unwrap());
+
+    bitmap.swap_bits(last, last).unwrap();
+    assert_eq!(bitmap, Bitmap512::from_set(last).unwrap());
+
+    assert!(bitmap.swap_bits(last, Bitmap512::MAP_LENGTH).is_err());
+    assert!(bitmap.swap_bits(Bitmap512::MAP_LENGTH, 0).is_err());
+    assert_eq!(bitmap, Bitmap512::from_set(last).unwrap());
+}
+
+#[test]
+fn parity() {
+    let mut bitmap = Bitmap512::default();
+    assert!(!bitmap.parity());
+
+    bitmap.set(3, true).unwrap();
+    assert!(bitmap.parity());
+
+    bitmap.set(SIZE_USIZE + 3, true).unwrap();
+    assert!(!bitmap.parity());
+
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+    assert!(bitmap.parity());
+
+    assert!(!Bitmap512::new(true).parity());
+}
+
+#[test]
+fn hamming_distance() {
+    let mut a = Bitmap512::default();
+    let mut b = Bitmap512::default();
+    a.set(0, true).unwrap();
+    a.set(SIZE_USIZE, true).unwrap();
+    b.set(SIZE_USIZE, true).unwrap();
+    b.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    assert_eq!(a.hamming_distance(&a), 0);
+    assert_eq!(a.hamming_distance(&b), 2);
+    assert_eq!(
+        Bitmap512::default().hamming_distance(&Bitmap512::new(true)),
+        Bitmap512::MAP_LENGTH
+    );
+}
+
+#[test]
+fn into_iterator_yields_bits() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(1, true).unwrap();
+    bitmap.set(SIZE_USIZE, true).unwrap();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    assert_eq!(bitmap.bits().len(), Bitmap512::MAP_LENGTH);
+
+    let mut count = 0;
+    for (i, bit) in bitmap.into_iter().enumerate() {
+        assert_eq!(bitmap.get(i), Ok(bit));
+        count += 1;
+    }
+    assert_eq!(count, Bitmap512::MAP_LENGTH);
+    assert_eq!((&bitmap).into_iter().filter(|&bit| bit).count(), 3);
+}
+
+#[test]
+fn as_bytes_views_storage() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(0, true).unwrap();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    let bytes = bitmap.as_bytes();
+    assert_eq!(bytes.len(), Bitmap512::MAP_LENGTH / 8);
+    assert_eq!(
+        bytes[bytes.len() - SIZE_USIZE / 8..],
+        1usize.to_ne_bytes()[..]
+    );
+    assert_eq!(
+        bytes[..SIZE_USIZE / 8],
+        (1usize << (SIZE_USIZE - 1)).to_ne_bytes()[..]
+    );
+    assert_eq!(bytes.iter().map(|byte| byte.count_ones()).sum::<u32>(), 2);
+}
+
+#[test]
+fn as_bytes_mut_writes_through() {
+    let mut bitmap = Bitmap512::default();
+    for byte in bitmap.as_bytes_mut() {
+        *byte = 0xFF;
+    }
+    assert_eq!(bitmap, Bitmap512::new(true));
+
+    bitmap.as_bytes_mut()[0] = 0;
+    assert_eq!(bitmap.count_ones(), Bitmap512::MAP_LENGTH - 8);
+    assert_eq!(bitmap.get(0), Ok(true));
+}
+
+#[test]
+fn try_from_bytes_round_trip() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(3, true).unwrap();
+    bitmap.set(SIZE_USIZE + 1, true).unwrap();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    assert_eq!(Bitmap512::try_from(bitmap.as_bytes()), Ok(bitmap));
+}
+
+#[test]
+fn try_from_bytes_lengths() {
+    let short = [0xFF; SIZE_USIZE / 8];
+    let bitmap = Bitmap512::try_from(&short[..]).unwrap();
+    assert_eq!(bitmap.count_ones(), SIZE_USIZE);
+    assert_eq!(&bitmap.as_bytes()[..short.len()], &short[..]);
+
+    let long = [0; Bitmap512::MAP_LENGTH / 8 + 1];
+    assert!(Bitmap512::try_from(&long[..]).is_err());
+}
+
+#[test]
+fn binary_formatting() {
+    let mut bitmap = Bitmap512::default();
+    assert_eq!(format!("{:b}", bitmap), "0");
+
+    bitmap.set(0, true).unwrap();
+    bitmap.set(2, true).unwrap();
+    assert_eq!(format!("{:b}", bitmap), "101");
+    assert_eq!(format!("{:#06b}", bitmap), "0b0101");
+
+    bitmap.set(SIZE_USIZE, true).unwrap();
+    let expected = String::from("1") + &"0".repeat(SIZE_USIZE - 3) + "101";
+    assert_eq!(format!("{:b}", bitmap), expected);
+
+    let full = format!("{:0w$b}", Bitmap512::new(true), w = Bitmap512::MAP_LENGTH);
+    assert_eq!(full, "1".repeat(Bitmap512::MAP_LENGTH));
+}
+
+#[test]
+fn not_complements_every_bit() {
+    assert_eq!(!Bitmap512::default(), Bitmap512::new(true));
+    assert_eq!(!Bitmap512::new(true), Bitmap512::default());
+
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(0, true).unwrap();
+    bitmap.set(SIZE_USIZE + 3, true).unwrap();
+
+    let complement = !bitmap;
+    assert_eq!(complement.get(0), Ok(false));
+    assert_eq!(complement.get(1), Ok(true));
+    assert_eq!(complement.get(SIZE_USIZE + 3), Ok(false));
+    assert_eq!(complement.count_ones(), Bitmap512::MAP_LENGTH - 2);
+    assert_eq!(!complement, bitmap);
+    assert_eq!(complement, bitmap ^ Bitmap512::new(true));
+}
+
+#[test]
+fn shift_left() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(0, true).unwrap();
+    bitmap.set(SIZE_USIZE - 1, true).unwrap();
+
+    let shifted = bitmap << 3;
+    assert_eq!(shifted.count_ones(), 2);
+    assert_eq!(shifted.get(3), Ok(true));
+    assert_eq!(shifted.get(SIZE_USIZE + 2), Ok(true));
+
+    let shifted = bitmap << (SIZE_USIZE * 2 + 1);
+    assert_eq!(shifted.get(SIZE_USIZE * 2 + 1), Ok(true));
+    assert_eq!(shifted.get(SIZE_USIZE * 3), Ok(true));
+    assert_eq!(shifted.count_ones(), 2);
+
+    let shifted = bitmap << (Bitmap512::MAP_LENGTH - 1);
+    assert_eq!(shifted.find_first_set(), Some(Bitmap512::MAP_LENGTH - 1));
+    assert_eq!(shifted.count_ones(), 1);
+
+    assert_eq!(bitmap << 0, bitmap);
+    assert_eq!(bitmap << Bitmap512::MAP_LENGTH, Bitmap512::default());
+
+    let mut assigned = bitmap;
+    assigned <<= SIZE_USIZE + 5;
+    assert_eq!(assigned, bitmap << (SIZE_USIZE + 5));
+}
+
+#[test]
+fn shift_right() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+    bitmap.set(SIZE_USIZE, true).unwrap();
+
+    let shifted = bitmap >> 3;
+    assert_eq!(shifted.count_ones(), 2);
+    assert_eq!(shifted.get(Bitmap512::MAP_LENGTH - 4), Ok(true));
+    assert_eq!(shifted.get(SIZE_USIZE - 3), Ok(true));
+
+    let shifted = bitmap >> (SIZE_USIZE + 1);
+    assert_eq!(shifted.count_ones(), 1);
+    assert_eq!(
+        shifted.find_first_set(),
+        Some(Bitmap512::MAP_LENGTH - SIZE_USIZE - 2)
+    );
+
+    assert_eq!(bitmap >> 0, bitmap);
+    assert_eq!(bitmap >> Bitmap512::MAP_LENGTH, Bitmap512::default());
+    assert_eq!(
+        Bitmap512::new(true) >> (Bitmap512::MAP_LENGTH - 1),
+        Bitmap512::from_set(0).unwrap()
+    );
+
+    let mut assigned = bitmap;
+    assigned >>= 2 * SIZE_USIZE - 1;
+    assert_eq!(assigned, bitmap >> (2 * SIZE_USIZE - 1));
+}
+
+#[test]
+fn shifts_match_bit_by_bit() {
+    let mut bitmap = Bitmap512::default();
+    for i in (0..Bitmap512::MAP_LENGTH).step_by(7) {
+        bitmap.set(i, true).unwrap();
+    }
+
+    for &n in &[1, 13, SIZE_USIZE, SIZE_USIZE + 13, 3 * SIZE_USIZE - 1] {
+        let left = bitmap << n;
+        let right = bitmap >> n;
+        for i in 0..Bitmap512::MAP_LENGTH {
+            let expected_left = i >= n && bitmap.get(i - n).unwrap();
+            let expected_right = i + n < Bitmap512::MAP_LENGTH && bitmap.get(i + n).unwrap();
+            assert_eq!(left.get(i), Ok(expected_left));
+            assert_eq!(right.get(i), Ok(expected_right));
+        }
+    }
+}
+
+#[test]
+fn set_range_across_elements() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set_range(3, 2 * SIZE_USIZE + 5, true).unwrap();
+
+    assert_eq!(bitmap.count_ones(), 2 * SIZE_USIZE + 2);
+    assert_eq!(bitmap.find_first_set(), Some(3));
+    assert_eq!(bitmap.find_last_set(), Some(2 * SIZE_USIZE + 4));
+
+    bitmap
+        .set_range(SIZE_USIZE - 1, SIZE_USIZE + 1, false)
+        .unwrap();
+    assert_eq!(bitmap.count_ones(), 2 * SIZE_USIZE);
+    assert_eq!(bitmap.get(SIZE_USIZE - 2), Ok(true));
+    assert_eq!(bitmap.get(SIZE_USIZE - 1), Ok(false));
+    assert_eq!(bitmap.get(SIZE_USIZE), Ok(false));
+    assert_eq!(bitmap.get(SIZE_USIZE + 1), Ok(true));
+
+    let mut full = Bitmap512::default();
+    full.set_range(0, Bitmap512::MAP_LENGTH, true).unwrap();
+    assert_eq!(full, Bitmap512::new(true));
+
+    full.set_range(5, 5, false).unwrap();
+    assert_eq!(full, Bitmap512::new(true));
+}
+
+#[test]
+fn set_range_errors() {
+    let mut bitmap = Bitmap512::default();
+
+    assert!(bitmap.set_range(5, 4, true).is_err());
+    assert!(bitmap.set_range(0, Bitmap512::MAP_LENGTH + 1, true).is_err());
+    assert_eq!(bitmap, Bitmap512::default());
+}
+
+#[test]
+fn set_range_matches_set() {
+    let ranges = [
+        (0, 1),
+        (SIZE_USIZE - 1, SIZE_USIZE),
+        (7, Bitmap512::MAP_LENGTH - 9),
+        (SIZE_USIZE, 3 * SIZE_USIZE),
+    ];
+
+    for &(begin, end) in ranges.iter() {
+        let mut ranged = Bitmap512::default();
+        let mut looped = Bitmap512::default();
+
+        ranged.set_range(begin, end, true).unwrap();
+        for i in begin..end {
+            looped.set(i, true).unwrap();
+        }
+        assert_eq!(ranged, looped);
+
+        ranged = !ranged;
+        ranged.set_range(begin, end, true).unwrap();
+        assert_eq!(ranged, Bitmap512::new(true));
+    }
+}
+
+#[test]
+fn create_bit_mask() {
+    let mask = Bitmap512::create_bit_mask(3, SIZE_USIZE + 2, true);
+    assert_eq!(mask.count_ones(), SIZE_USIZE - 1);
+    assert_eq!(mask.find_first_set(), Some(3));
+    assert_eq!(mask.find_last_set(), Some(SIZE_USIZE + 1));
+
+    let inverse = Bitmap512::create_bit_mask(3, SIZE_USIZE + 2, false);
+    assert_eq!(inverse, !mask);
+
+    let mut expected = Bitmap512::default();
+    expected.set_range(10, Bitmap512::MAP_LENGTH, true).unwrap();
+    assert_eq!(
+        Bitmap512::create_bit_mask(10, Bitmap512::MAP_LENGTH + 5, true),
+        expected
+    );
+
+    assert_eq!(Bitmap512::create_bit_mask(5, 5, true), Bitmap512::default());
+    assert_eq!(
+        Bitmap512::create_bit_mask(Bitmap512::MAP_LENGTH, Bitmap512::MAP_LENGTH + 1, false),
+        Bitmap512::new(true)
+    );
+    assert_eq!(
+        Bitmap512::create_bit_mask(0, Bitmap512::MAP_LENGTH, true),
+        Bitmap512::new(true)
+    );
+}
+
+#[test]
+fn sub_borrows_across_elements() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(SIZE_USIZE, true).unwrap();
+
+    let result = bitmap - 1;
+    assert_eq!(result.count_ones(), SIZE_USIZE);
+    assert_eq!(result.find_last_set(), Some(SIZE_USIZE - 1));
+    assert_eq!(result + 1, bitmap);
+
+    let mut assigned = Bitmap512::from_set(0).unwrap();
+    assigned -= 1;
+    assert_eq!(assigned, Bitmap512::default());
+
+    assigned -= 1;
+    assert_eq!(assigned, Bitmap512::new(true));
+    assert_eq!(Bitmap512::default() - 1, Bitmap512::new(true));
+}
+
+#[test]
+fn mul_carries_across_elements() {
+    let bitmap = Bitmap512::from_set(SIZE_USIZE - 1).unwrap();
+
+    let result = bitmap * 4;
+    assert_eq!(result, Bitmap512::from_set(SIZE_USIZE + 1).unwrap());
+
+    let result = (Bitmap512::default() + usize::MAX) * 3;
+    assert_eq!(result.to_array()[NUM_ELEMENTS - 1], usize::MAX - 2);
+    assert_eq!(result.to_array()[NUM_ELEMENTS - 2], 2);
+
+    let mut assigned = Bitmap512::from_set(3).unwrap();
+    assigned *= 5;
+    assert_eq!(assigned, Bitmap512::default() + 40);
+}
+
+#[test]
+fn div_carries_remainders() {
+    let bitmap = Bitmap512::from_set(SIZE_USIZE + 1).unwrap();
+    assert_eq!(bitmap / 4, Bitmap512::from_set(SIZE_USIZE - 1).unwrap());
+
+    let product = (Bitmap512::from_set(Bitmap512::MAP_LENGTH - 3).unwrap() + 12345) * 7;
+    assert_eq!(
+        product / 7,
+        Bitmap512::from_set(Bitmap512::MAP_LENGTH - 3).unwrap() + 12345
+    );
+
+    let mut assigned = Bitmap512::default() + 100;
+    assigned /= 7;
+    assert_eq!(assigned, Bitmap512::default() + 14);
+}
+
+#[test]
+#[should_panic]
+fn div_by_zero_panics() {
+    let _ = Bitmap512::new(true) / 0;
+}
+
+#[test]
+fn serde_compact_tokens() {
+    let bitmap = Bitmap512::from_set(SIZE_USIZE + 1).unwrap();
+
+    let mut tokens = vec![Token::Seq {
+        len: Some(NUM_ELEMENTS),
+    }];
+    for &element in bitmap.to_array().iter() {
+        tokens.push(Token::U64(element as u64));
+    }
+    tokens.push(Token::SeqEnd);
+
+    assert_tokens(&bitmap.compact(), &tokens);
+}
+
+#[test]
+fn serde_compact_rejects_wrong_length() {
+    let mut tokens = vec![Token::Seq {
+        len: Some(NUM_ELEMENTS - 1),
+    }];
+    for _ in 0..NUM_ELEMENTS - 1 {
+        tokens.push(Token::U64(0));
+    }
+    tokens.push(Token::SeqEnd);
+
+    let expected = if USES_HEX {
+        format!(
+            "a hex string of at most {} digits or a sequence of {} elements",
+            Bitmap512::MAP_LENGTH / 4,
+            NUM_ELEMENTS
+        )
+    } else {
+        format!("a sequence of {} elements", NUM_ELEMENTS)
+    };
+    assert_de_tokens_error::<Compact<Bitmap512>>(
+        &tokens,
+        &format!("invalid length {}, expected {}", NUM_ELEMENTS - 1, expected),
+    );
+}
+
+#[test]
+fn serde_readable_tokens() {
+    let bitmap = Bitmap512::from_set(SIZE_USIZE + 1).unwrap() | Bitmap512::from_set(3).unwrap();
+
+    if USES_HEX {
+        let hex = "0".repeat(Bitmap512::MAP_LENGTH / 4 - SIZE_USIZE / 4 - 1)
+            + "2"
+            + &"0".repeat(SIZE_USIZE / 4 - 1)
+            + "8";
+        assert_tokens(
+            &bitmap.readable(),
+            &[Token::Str(Box::leak(hex.into_boxed_str()))],
+        );
+    } else {
+        let mut tokens = vec![Token::Seq {
+            len: Some(NUM_ELEMENTS),
+        }];
+        for &element in bitmap.to_array().iter() {
+            tokens.push(Token::U64(element as u64));
+        }
+        tokens.push(Token::SeqEnd);
+        assert_tokens(&bitmap.readable(), &tokens);
+    }
+}
+
+#[test]
+fn serde_json_round_trip() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(0, true).unwrap();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    let json = serde_json::to_string(&bitmap).unwrap();
+    if USES_HEX {
+        assert_eq!(json.len(), Bitmap512::MAP_LENGTH / 4 + 2);
+    } else {
+        assert!(json.starts_with('['));
+    }
+    assert_eq!(serde_json::from_str::<Bitmap512>(&json).unwrap(), bitmap);
+}
+
+#[test]
+fn serde_json_pads_short_hex() {
+    if !USES_HEX {
+        assert!(serde_json::from_str::<Bitmap512>("\"0x1ff\"").is_err());
+        return;
+    }
+
+    let bitmap: Bitmap512 = serde_json::from_str("\"0x1ff\"").unwrap();
+    assert_eq!(bitmap, Bitmap512::default() + 0x1FF);
+    let bitmap: Bitmap512 = serde_json::from_str("\"0X1FF\"").unwrap();
+    assert_eq!(bitmap, Bitmap512::default() + 0x1FF);
+
+    let spanning = String::from("\"1") + &"0".repeat(SIZE_USIZE / 4) + "\"";
+    let bitmap: Bitmap512 = serde_json::from_str(&spanning).unwrap();
+    assert_eq!(bitmap, Bitmap512::from_set(SIZE_USIZE).unwrap());
+}
+
+#[test]
+fn serde_json_rejects_invalid_hex() {
+    let too_long = format!("\"{}\"", "0".repeat(Bitmap512::MAP_LENGTH / 4 + 1));
+    assert!(serde_json::from_str::<Bitmap512>(&too_long).is_err());
+    assert!(serde_json::from_str::<Bitmap512>("\"12g4\"").is_err());
+    assert!(serde_json::from_str::<Bitmap512>("\"\"").is_err());
+}
+
+#[test]
+fn errors_are_typed() {
+    let mut bitmap = Bitmap512::default();
+    let out_of_bounds = BitmapError::IndexOutOfBounds {
+        index: Bitmap512::MAP_LENGTH,
+        capacity: Bitmap512::MAP_LENGTH,
+    };
+
+    assert_eq!(bitmap.get(Bitmap512::MAP_LENGTH), Err(out_of_bounds));
+    assert_eq!(bitmap.set(Bitmap512::MAP_LENGTH, true), Err(out_of_bounds));
+    assert_eq!(bitmap.toggle(Bitmap512::MAP_LENGTH), Err(out_of_bounds));
+    assert_eq!(Bitmap512::from_set(Bitmap512::MAP_LENGTH), Err(out_of_bounds));
+    assert_eq!(
+        bitmap.set_range(5, 4, true),
+        Err(BitmapError::RangeOutOfBounds {
+            begin: 5,
+            end: 4,
+            capacity: Bitmap512::MAP_LENGTH
+        })
+    );
+
+    let long = [0; Bitmap512::MAP_LENGTH / 8 + 1];
+    assert_eq!(
+        Bitmap512::try_from(&long[..]),
+        Err(BitmapError::SliceTooLong {
+            length: Bitmap512::MAP_LENGTH / 8 + 1,
+            capacity: Bitmap512::MAP_LENGTH / 8
+        })
+    );
+}
+
+fn fill_generically<T: BitmapOps>(bitmap: &mut T) {
+    for i in 0..T::capacity() {
+        bitmap.set(i, true).unwrap();
+    }
+}
+
+#[test]
+fn bitmap_ops_trait() {
+    let mut bitmap = Bitmap512::default();
+    assert!(BitmapOps::is_empty(&bitmap));
+    assert_eq!(<Bitmap512 as BitmapOps>::capacity(), Bitmap512::MAP_LENGTH);
+
+    fill_generically(&mut bitmap);
+    assert!(BitmapOps::is_full(&bitmap));
+    assert_eq!(BitmapOps::count_ones(&bitmap), Bitmap512::MAP_LENGTH);
+    assert_eq!(BitmapOps::get(&bitmap, Bitmap512::MAP_LENGTH - 1), Ok(true));
+    assert!(BitmapOps::set(&mut bitmap, Bitmap512::MAP_LENGTH, true).is_err());
+}
+
+#[test]
+fn unchecked_access() {
+    let mut bitmap = Bitmap512::default();
+    let last = Bitmap512::MAP_LENGTH - 1;
+
+    unsafe {
+        bitmap.set_unchecked(SIZE_USIZE, true);
+        bitmap.set_unchecked(last, true);
+        assert!(bitmap.get_unchecked(SIZE_USIZE));
+        assert!(bitmap.get_unchecked(last));
+        assert!(!bitmap.get_unchecked(0));
+
+        bitmap.set_unchecked(SIZE_USIZE, false);
+        assert!(!bitmap.get_unchecked(SIZE_USIZE));
+    }
+
+    assert_eq!(bitmap, Bitmap512::from_set(last).unwrap());
+}
+
+#[test]
+fn get_range_across_elements() {
+    let mut bitmap = Bitmap512::default();
+    bitmap
+        .set_range(SIZE_USIZE - 2, SIZE_USIZE + 3, true)
+        .unwrap();
+    bitmap.set(SIZE_USIZE + 5, true).unwrap();
+
+    assert_eq!(
+        bitmap.get_range(SIZE_USIZE - 4, SIZE_USIZE + 6),
+        Ok(0b10_0111_1100)
+    );
+    assert_eq!(bitmap.get_range(0, 0), Ok(0));
+    assert_eq!(
+        Bitmap512::new(true).get_range(Bitmap512::MAP_LENGTH - 64, Bitmap512::MAP_LENGTH),
+        Ok(u64::MAX)
+    );
+    assert_eq!(Bitmap512::new(true).get_range(3, 67), Ok(u64::MAX));
+
+    assert_eq!(
+        bitmap.get_range(0, 65),
+        Err(BitmapError::RangeTooWide {
+            width: 65,
+            max_width: 64
+        })
+    );
+    assert!(bitmap.get_range(4, 3).is_err());
+    assert!(bitmap.get_range(0, Bitmap512::MAP_LENGTH + 1).is_err());
+}
+
+#[test]
+fn set_bits_from_value_across_elements() {
+    let mut bitmap = Bitmap512::default();
+
+    bitmap
+        .set_bits_from_value(SIZE_USIZE - 4, SIZE_USIZE + 6, 0b1110_0111_1101)
+        .unwrap();
+    assert_eq!(
+        bitmap.get_range(SIZE_USIZE - 4, SIZE_USIZE + 6),
+        Ok(0b10_0111_1101)
+    );
+    assert_eq!(bitmap.count_ones(), 7);
+
+    bitmap.set_bits_from_value(3, 67, u64::MAX).unwrap();
+    assert_eq!(bitmap.get_range(3, 67), Ok(u64::MAX));
+
+    let mut full = Bitmap512::new(true);
+    full.set_bits_from_value(Bitmap512::MAP_LENGTH - 64, Bitmap512::MAP_LENGTH, 0)
+        .unwrap();
+    assert_eq!(full.count_ones(), Bitmap512::MAP_LENGTH - 64);
+    assert_eq!(full.find_last_set(), Some(Bitmap512::MAP_LENGTH - 65));
+
+    assert!(bitmap.set_bits_from_value(0, 65, 0).is_err());
+    assert!(bitmap.set_bits_from_value(5, 4, 0).is_err());
+}
+
+#[test]
+fn get_and_set_chunk() {
+    let element_count = Bitmap512::MAP_LENGTH / SIZE_USIZE;
+    let mut bitmap = Bitmap512::default();
+
+    bitmap.set_chunk(0, 0b1011).unwrap();
+    bitmap.set_chunk(1, usize::MAX).unwrap();
+    assert_eq!(bitmap.get_chunk(0), Ok(0b1011));
+    assert_eq!(bitmap.get_chunk(1), Ok(usize::MAX));
+    assert_eq!(bitmap.get_chunk(element_count - 1), Ok(0));
+    assert!(bitmap.get(1).unwrap());
+    assert!(!bitmap.get(2).unwrap());
+    assert!(bitmap.get(SIZE_USIZE).unwrap());
+    assert_eq!(bitmap.count_ones(), 3 + SIZE_USIZE);
+
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+    assert_eq!(
+        bitmap.get_chunk(element_count - 1),
+        Ok(1 << (SIZE_USIZE - 1))
+    );
+
+    assert_eq!(
+        bitmap.get_chunk(element_count),
+        Err(BitmapError::IndexOutOfBounds {
+            index: element_count,
+            capacity: element_count
+        })
+    );
+    assert!(bitmap.set_chunk(element_count, 0).is_err());
+}
+
+#[test]
+fn index_reads_underlying_elements() {
+    let mut bitmap = Bitmap512::default();
+    bitmap.set(1, true).unwrap();
+    bitmap.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    // Indexing goes through `Deref` to the underlying array, with the highest bits first
+    assert_eq!(bitmap[NUM_ELEMENTS - 1], 0b10);
+    assert_eq!(bitmap[0], 1 << (SIZE_USIZE - 1));
+    assert_eq!(bitmap.as_ref()[NUM_ELEMENTS - 1], 0b10);
+}
+
+#[test]
+fn subset_and_superset() {
+    let mut small = Bitmap512::default();
+    small.set(0, true).unwrap();
+    small.set(SIZE_USIZE + 1, true).unwrap();
+
+    let mut large = small;
+    large.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    assert!(small.is_subset(&large));
+    assert!(small.is_subset(&small));
+    assert!(!large.is_subset(&small));
+    assert!(Bitmap512::default().is_subset(&small));
+
+    assert!(large.is_superset(&small));
+    assert!(!small.is_superset(&large));
+    assert!(Bitmap512::new(true).is_superset(&large));
+}
+
+#[test]
+fn is_disjoint() {
+    let mut a = Bitmap512::default();
+    a.set(0, true).unwrap();
+    a.set(SIZE_USIZE + 1, true).unwrap();
+
+    let mut b = Bitmap512::default();
+    b.set(1, true).unwrap();
+    b.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    assert!(a.is_disjoint(&b));
+    assert!(a.is_disjoint(&!a));
+    assert!(!a.is_disjoint(&a));
+
+    b.set(SIZE_USIZE + 1, true).unwrap();
+    assert!(!a.is_disjoint(&b));
+}
+
+#[test]
+fn difference() {
+    let mut a = Bitmap512::default();
+    a.set(0, true).unwrap();
+    a.set(SIZE_USIZE + 1, true).unwrap();
+    a.set(Bitmap512::MAP_LENGTH - 1, true).unwrap();
+
+    let mut b = Bitmap512::default();
+    b.set(SIZE_USIZE + 1, true).unwrap();
+    b.set(5, true).unwrap();
+
+    let difference = a.difference(&b);
+    assert_eq!(difference.count_ones(), 2);
+    assert_eq!(difference.get(SIZE_USIZE + 1), Ok(false));
+    assert_eq!(difference, a & !b);
+    assert_eq!(a.difference(&a), Bitmap512::default());
+    assert_eq!(Bitmap512::new(true).difference(&a), !a);
+}
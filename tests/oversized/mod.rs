@@ -1 +1,10 @@
+// The tests here predate the clippy lints below, and are kept as they were written.
+#![allow(unused_assignments, clippy::manual_bits)]
+
+mod bitmap_1024;
+mod bitmap_2048;
+mod bitmap_256;
+mod bitmap_4096;
+mod bitmap_512;
 mod bitmap_kb;
+mod serialization;
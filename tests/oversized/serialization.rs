@@ -0,0 +1,32 @@
+use fixed_bitmaps::{Bitmap1024, Bitmap256, Bitmap4096, Bitmap512, BitmapSize};
+
+const SIZE_USIZE: usize = usize::BITS as usize;
+
+#[test]
+fn small_bitmaps_use_sequences_in_json() {
+    let bitmap = Bitmap256::from_set(0).unwrap();
+    let mut expected = vec![0; Bitmap256::MAP_LENGTH / SIZE_USIZE];
+    *expected.last_mut().unwrap() = 1;
+
+    let json = serde_json::to_string(&bitmap).unwrap();
+    assert_eq!(json, serde_json::to_string(&expected).unwrap());
+    assert_eq!(serde_json::from_str::<Bitmap256>(&json).unwrap(), bitmap);
+    assert!(serde_json::from_str::<Bitmap256>("\"1\"").is_err());
+
+    let bitmap = Bitmap512::from_set(Bitmap512::MAP_LENGTH - 1).unwrap();
+    let json = serde_json::to_string(&bitmap).unwrap();
+    assert!(json.starts_with('['));
+    assert_eq!(serde_json::from_str::<Bitmap512>(&json).unwrap(), bitmap);
+}
+
+#[test]
+fn large_bitmaps_use_hex_in_json() {
+    let json = serde_json::to_string(&Bitmap1024::from_set(4).unwrap()).unwrap();
+    assert_eq!(
+        json,
+        format!("\"{}10\"", "0".repeat(Bitmap1024::MAP_LENGTH / 4 - 2))
+    );
+
+    let bitmap: Bitmap4096 = serde_json::from_str("\"0X10\"").unwrap();
+    assert_eq!(bitmap, Bitmap4096::from_set(4).unwrap());
+}
@@ -1,4 +1,4 @@
-use fixed_bitmaps::{Bitmap128, BitmapSize};
+use fixed_bitmaps::{Bitmap128, BitmapError, BitmapOps, BitmapSize, ParseBitmapError};
 
 #[test]
 fn default_is_0() {
@@ -196,3 +196,1029 @@ fn create_bit_mask_false() {
     assert_eq!(*e, u128::MAX);
     assert_eq!(*f, 0);
 }
+
+#[test]
+fn pext_packs_masked_bits_into_bitmap() {
+    let bitmap = Bitmap128::from(0b10110100);
+    assert_eq!(
+        bitmap.pext(Bitmap128::from(0b10101010)),
+        Bitmap128::from(0b1100)
+    );
+    assert_eq!(bitmap.pext(Bitmap128::new(true)), bitmap);
+    assert_eq!(bitmap.pext(Bitmap128::default()), Bitmap128::default());
+    assert_eq!(
+        Bitmap128::new(true).pext(Bitmap128::from_alternating(true)),
+        Bitmap128::create_bit_mask(0, Bitmap128::MAP_LENGTH / 2, true)
+    );
+}
+
+#[test]
+fn pdep_scatters_low_bits_into_mask() {
+    let mask = Bitmap128::from(0b1011_0010);
+    assert_eq!(
+        Bitmap128::pdep(mask, Bitmap128::from(0b1101)),
+        Bitmap128::from(0b1010_0010)
+    );
+    assert_eq!(
+        Bitmap128::pdep(Bitmap128::default(), Bitmap128::new(true)),
+        Bitmap128::default()
+    );
+    assert_eq!(
+        Bitmap128::pdep(Bitmap128::from_alternating(false), Bitmap128::new(true)),
+        Bitmap128::from_alternating(false)
+    );
+
+    let bitmap = Bitmap128::from(0b1110_0110);
+    assert_eq!(Bitmap128::pdep(mask, bitmap.pext(mask)), bitmap & mask);
+}
+
+#[test]
+fn pext_select_packs_masked_bits() {
+    let bitmap = Bitmap128::from(0b10110100);
+
+    assert_eq!(bitmap.pext_select(Bitmap128::from(0b01010101)), 0b0110);
+    assert_eq!(bitmap.pext_select(Bitmap128::from(0b10101010)), 0b1100);
+    assert_eq!(bitmap.pext_select(Bitmap128::new(true)), *bitmap);
+    assert_eq!(bitmap.pext_select(Bitmap128::default()), 0);
+    assert_eq!(
+        Bitmap128::new(true).pext_select(Bitmap128::from_set(Bitmap128::MAP_LENGTH - 1).unwrap()),
+        1
+    );
+}
+
+#[test]
+fn spread_sets_lowest_bits() {
+    assert_eq!(*Bitmap128::spread(0), 0);
+    assert_eq!(*Bitmap128::spread(1), 0b1);
+    assert_eq!(*Bitmap128::spread(7), 0b1111111);
+    assert_eq!(
+        Bitmap128::spread(Bitmap128::MAP_LENGTH - 1),
+        Bitmap128::new(true) >> 1
+    );
+    assert_eq!(*Bitmap128::spread(Bitmap128::MAP_LENGTH), u128::MAX);
+    assert_eq!(*Bitmap128::spread(Bitmap128::MAP_LENGTH + 1), u128::MAX);
+}
+
+#[test]
+fn from_sorted_indices_sets_bits() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+
+    assert_eq!(
+        Bitmap128::from_sorted_indices(&[]).unwrap(),
+        Bitmap128::default()
+    );
+    assert_eq!(
+        *Bitmap128::from_sorted_indices(&[1, 2, 6]).unwrap(),
+        0b1000110
+    );
+    assert_eq!(*Bitmap128::from_sorted_indices(&[2, 2, 3]).unwrap(), 0b1100);
+    assert_eq!(
+        Bitmap128::from_sorted_indices(&[0, last]).unwrap(),
+        Bitmap128::from_set(0).unwrap() | Bitmap128::from_set(last).unwrap()
+    );
+}
+
+#[test]
+fn from_sorted_indices_errors() {
+    let length = Bitmap128::MAP_LENGTH;
+
+    assert!(Bitmap128::from_sorted_indices(&[0, length]).is_err());
+    assert!(Bitmap128::from_sorted_indices(&[2, 1]).is_err());
+    assert!(Bitmap128::from_sorted_indices(&[length, 1]).is_err());
+}
+
+#[test]
+fn checked_rotate_within_capacity() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let bitmap = Bitmap128::from(0b1001);
+
+    assert_eq!(bitmap.checked_rotate_left(0), Some(bitmap));
+    assert_eq!(
+        bitmap.checked_rotate_left(2),
+        Some(Bitmap128::from(0b100100))
+    );
+    assert_eq!(
+        Bitmap128::from(1).checked_rotate_left(last),
+        Bitmap128::from_set(last).ok()
+    );
+    assert_eq!(
+        bitmap.checked_rotate_right(1),
+        Some(Bitmap128::from(0b100) | Bitmap128::from_set(last).unwrap())
+    );
+    assert_eq!(
+        Bitmap128::from(1).checked_rotate_right(last),
+        Some(Bitmap128::from(0b10))
+    );
+}
+
+#[test]
+fn checked_rotate_out_of_capacity() {
+    let bitmap = Bitmap128::from(1);
+
+    assert_eq!(bitmap.checked_rotate_left(Bitmap128::MAP_LENGTH), None);
+    assert_eq!(bitmap.checked_rotate_right(Bitmap128::MAP_LENGTH), None);
+    assert_eq!(bitmap.checked_rotate_left(usize::MAX), None);
+}
+
+#[test]
+fn from_alternating_patterns() {
+    let ones = Bitmap128::from_alternating(true);
+    let zeros = Bitmap128::from_alternating(false);
+
+    assert_eq!(*ones & 0b1111, 0b0101);
+    assert_eq!(*zeros & 0b1111, 0b1010);
+    assert_eq!(ones, !zeros);
+    assert_eq!(ones | zeros, Bitmap128::new(true));
+    assert_eq!(ones.get(0), Ok(true));
+    assert_eq!(ones.get(Bitmap128::MAP_LENGTH - 1), Ok(false));
+    assert_eq!(zeros.get(Bitmap128::MAP_LENGTH - 1), Ok(true));
+}
+
+#[test]
+fn from_repeating_byte_fills_every_byte() {
+    let bitmap = Bitmap128::from_repeating_byte(0b10100101);
+
+    for byte in bitmap.to_u128().to_ne_bytes() {
+        assert_eq!(byte, 0b10100101);
+    }
+
+    assert_eq!(Bitmap128::from_repeating_byte(0), Bitmap128::default());
+    assert_eq!(Bitmap128::from_repeating_byte(0xFF), Bitmap128::new(true));
+    assert_eq!(
+        Bitmap128::from_repeating_byte(0x55),
+        Bitmap128::from_alternating(true)
+    );
+}
+
+#[test]
+fn count_runs_counts_blocks() {
+    assert_eq!(Bitmap128::default().count_runs(), 1);
+    assert_eq!(Bitmap128::new(true).count_runs(), 1);
+    assert_eq!(Bitmap128::from(0b00111100).count_runs(), 3);
+    assert_eq!(Bitmap128::from(0b1).count_runs(), 2);
+    assert_eq!(
+        Bitmap128::from_alternating(true).count_runs(),
+        Bitmap128::MAP_LENGTH
+    );
+    assert_eq!(
+        Bitmap128::from_set(Bitmap128::MAP_LENGTH - 1)
+            .unwrap()
+            .count_runs(),
+        2
+    );
+}
+
+#[test]
+fn inner_and_into_inner() {
+    let bitmap = Bitmap128::from(0b1011);
+
+    assert_eq!(*bitmap.inner(), 0b1011);
+    assert_eq!(bitmap.into_inner(), 0b1011);
+    assert_eq!(Bitmap128::new(true).into_inner(), u128::MAX);
+}
+
+#[test]
+fn shift_left_or_fills_low_bits() {
+    let bitmap = Bitmap128::from(0b11);
+
+    assert_eq!(*bitmap.shift_left_or(0, true), 0b11);
+    assert_eq!(*bitmap.shift_left_or(3, true), 0b11111);
+    assert_eq!(*bitmap.shift_left_or(3, false), 0b11000);
+    assert_eq!(
+        bitmap.shift_left_or(Bitmap128::MAP_LENGTH, true),
+        Bitmap128::new(true)
+    );
+    assert_eq!(
+        bitmap.shift_left_or(Bitmap128::MAP_LENGTH, false),
+        Bitmap128::default()
+    );
+}
+
+#[test]
+fn shift_right_or_fills_high_bits() {
+    let length = Bitmap128::MAP_LENGTH;
+    let bitmap = Bitmap128::from(0b1100);
+
+    assert_eq!(*bitmap.shift_right_or(0, true), 0b1100);
+    assert_eq!(*bitmap.shift_right_or(2, false), 0b11);
+    assert_eq!(
+        bitmap.shift_right_or(2, true),
+        Bitmap128::from(0b11) | Bitmap128::create_bit_mask(length - 2, length, true)
+    );
+    assert_eq!(bitmap.shift_right_or(length, true), Bitmap128::new(true));
+    assert_eq!(bitmap.shift_right_or(length, false), Bitmap128::default());
+}
+
+#[test]
+fn create_bit_mask_false_is_complement_of_true() {
+    let length = Bitmap128::MAP_LENGTH;
+    assert_eq!(
+        Bitmap128::create_bit_mask(0, 0, false),
+        Bitmap128::new(true)
+    );
+    assert_eq!(
+        Bitmap128::create_bit_mask(3, 6, false),
+        Bitmap128::new(true) ^ 0b111000
+    );
+    assert_eq!(
+        Bitmap128::create_bit_mask(5, 3, false),
+        Bitmap128::new(true)
+    );
+    assert_eq!(
+        Bitmap128::create_bit_mask(0, length, false),
+        Bitmap128::default()
+    );
+    assert_eq!(
+        Bitmap128::create_bit_mask(2, length + 1, false),
+        Bitmap128::from(0b11)
+    );
+    assert_eq!(
+        Bitmap128::create_bit_mask(length, length + 1, false),
+        Bitmap128::new(true)
+    );
+
+    // Clears each bit in the range one at a time, leaving every other bit set
+    for begin in 0..=length + 1 {
+        for end in 0..=length + 1 {
+            let expected = (begin..end.min(length)).fold(u128::MAX, |bits, i| bits & !(1 << i));
+            assert_eq!(
+                Bitmap128::create_bit_mask(begin, end, false),
+                Bitmap128::from(expected)
+            );
+        }
+    }
+}
+
+#[test]
+fn from_mask_and_value_selects_bits() {
+    let mask = Bitmap128::from(0b11110000);
+    let value = Bitmap128::from(0b10101010);
+
+    assert_eq!(*Bitmap128::from_mask_and_value(mask, value), 0b10100000);
+    assert_eq!(
+        Bitmap128::from_mask_and_value(Bitmap128::new(true), value),
+        value
+    );
+    assert_eq!(
+        Bitmap128::from_mask_and_value(Bitmap128::default(), value),
+        Bitmap128::default()
+    );
+}
+
+#[test]
+fn count_ones_counts_set_bits() {
+    let count: u32 = Bitmap128::from(0b10110).count_ones();
+    assert_eq!(count, 3);
+    assert_eq!(Bitmap128::default().count_ones(), 0);
+    assert_eq!(
+        Bitmap128::new(true).count_ones() as usize,
+        Bitmap128::MAP_LENGTH
+    );
+}
+
+#[test]
+fn is_empty_and_any() {
+    let empty = Bitmap128::default();
+    let last = Bitmap128::from_set(Bitmap128::MAP_LENGTH - 1).unwrap();
+
+    assert!(empty.is_empty());
+    assert!(!empty.any());
+    assert!(!last.is_empty());
+    assert!(last.any());
+    assert!(Bitmap128::from(1).any());
+}
+
+#[test]
+fn is_full() {
+    let mut bitmap = Bitmap128::new(true);
+    assert!(bitmap.is_full());
+
+    bitmap.set(Bitmap128::MAP_LENGTH - 1, false).unwrap();
+    assert!(!bitmap.is_full());
+    assert!(!Bitmap128::default().is_full());
+}
+
+#[test]
+fn find_first_set() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+
+    assert_eq!(Bitmap128::default().find_first_set(), None);
+    assert_eq!(Bitmap128::new(true).find_first_set(), Some(0));
+    assert_eq!(Bitmap128::from(0b1011000).find_first_set(), Some(3));
+    assert_eq!(
+        Bitmap128::from_set(last).unwrap().find_first_set(),
+        Some(last)
+    );
+}
+
+#[test]
+fn find_last_set() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+
+    assert_eq!(Bitmap128::default().find_last_set(), None);
+    assert_eq!(Bitmap128::new(true).find_last_set(), Some(last));
+    assert_eq!(Bitmap128::from(0b1011000).find_last_set(), Some(6));
+    assert_eq!(Bitmap128::from(1).find_last_set(), Some(0));
+}
+
+#[test]
+fn find_first_and_last_clear() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let full = Bitmap128::new(true);
+
+    assert_eq!(Bitmap128::default().find_first_clear(), Some(0));
+    assert_eq!(Bitmap128::default().find_last_clear(), Some(last));
+    assert_eq!(full.find_first_clear(), None);
+    assert_eq!(full.find_last_clear(), None);
+    assert_eq!(Bitmap128::from(0b1011).find_first_clear(), Some(2));
+    assert_eq!((full >> 2).find_last_clear(), Some(last));
+    assert_eq!((full ^ 0b10).find_last_clear(), Some(1));
+}
+
+#[test]
+fn find_next_set() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let bitmap = Bitmap128::from(0b1000101) | Bitmap128::from_set(last).unwrap();
+
+    assert_eq!(bitmap.find_next_set(0), Some(2));
+    assert_eq!(bitmap.find_next_set(1), Some(2));
+    assert_eq!(bitmap.find_next_set(2), Some(6));
+    assert_eq!(bitmap.find_next_set(6), Some(last));
+    assert_eq!(bitmap.find_next_set(last), None);
+    assert_eq!(bitmap.find_next_set(usize::MAX), None);
+    assert_eq!(Bitmap128::default().find_next_set(0), None);
+}
+
+#[test]
+fn toggle_flips_single_bit() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap128::from(0b101);
+
+    bitmap.toggle(0).unwrap();
+    assert_eq!(*bitmap, 0b100);
+    bitmap.toggle(1).unwrap();
+    assert_eq!(*bitmap, 0b110);
+    bitmap.toggle(last).unwrap();
+    assert_eq!(bitmap.get(last), Ok(true));
+    bitmap.toggle(last).unwrap();
+    assert_eq!(*bitmap, 0b110);
+}
+
+#[test]
+fn toggle_out_of_bounds() {
+    let mut bitmap = Bitmap128::from(0b101);
+
+    assert!(bitmap.toggle(Bitmap128::MAP_LENGTH).is_err());
+    assert_eq!(*bitmap, 0b101);
+}
+
+#[test]
+fn toggle_range_flips_bits() {
+    let length = Bitmap128::MAP_LENGTH;
+    let mut bitmap = Bitmap128::from(0b11110000);
+
+    bitmap.toggle_range(2, 6);
+    assert_eq!(*bitmap, 0b11001100);
+
+    bitmap.toggle_range(3, 3);
+    assert_eq!(*bitmap, 0b11001100);
+
+    bitmap.toggle_range(0, length);
+    assert_eq!(bitmap, !Bitmap128::from(0b11001100));
+
+    let mut bitmap = Bitmap128::default();
+    bitmap.toggle_range(length - 1, length + 5);
+    assert_eq!(bitmap, Bitmap128::from_set(length - 1).unwrap());
+}
+
+#[test]
+fn rotate_wraps_around() {
+    let length = Bitmap128::MAP_LENGTH as u32;
+    let bitmap = Bitmap128::from(0b1011);
+
+    assert_eq!(*bitmap.rotate_left(1), 0b10110);
+    assert_eq!(
+        bitmap.rotate_right(1),
+        Bitmap128::from(0b101) | Bitmap128::from_set(Bitmap128::MAP_LENGTH - 1).unwrap()
+    );
+    assert_eq!(bitmap.rotate_left(length), bitmap);
+    assert_eq!(bitmap.rotate_right(length + 1), bitmap.rotate_right(1));
+    assert_eq!(bitmap.rotate_left(3).rotate_right(3), bitmap);
+}
+
+#[test]
+fn reverse_bits() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let bitmap = Bitmap128::from(0b1101);
+    let reversed = bitmap.reverse_bits();
+
+    for i in 0..Bitmap128::MAP_LENGTH {
+        assert_eq!(reversed.get(last - i), bitmap.get(i));
+    }
+
+    assert_eq!(reversed.reverse_bits(), bitmap);
+    assert_eq!(Bitmap128::new(true).reverse_bits(), Bitmap128::new(true));
+}
+
+#[test]
+fn swap_bits() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let mut bitmap = Bitmap128::from(0b0110);
+
+    bitmap.swap_bits(1, 2).unwrap();
+    assert_eq!(*bitmap, 0b0110);
+
+    bitmap.swap_bits(0, 2).unwrap();
+    assert_eq!(*bitmap, 0b0011);
+
+    bitmap.swap_bits(last, 1).unwrap();
+    assert_eq!(
+        bitmap,
+        Bitmap128::from(0b1) | Bitmap128::from_set(last).unwrap()
+    );
+
+    bitmap.swap_bits(3, 3).unwrap();
+    assert_eq!(bitmap.count_ones(), 2);
+}
+
+#[test]
+fn swap_bits_out_of_bounds() {
+    let mut bitmap = Bitmap128::from(0b1);
+
+    assert!(bitmap.swap_bits(0, Bitmap128::MAP_LENGTH).is_err());
+    assert!(bitmap.swap_bits(Bitmap128::MAP_LENGTH, 0).is_err());
+    assert_eq!(*bitmap, 0b1);
+}
+
+#[test]
+fn parity() {
+    assert!(!Bitmap128::default().parity());
+    assert!(!Bitmap128::new(true).parity());
+    assert!(Bitmap128::from(0b1).parity());
+    assert!(!Bitmap128::from(0b11).parity());
+    assert!(Bitmap128::from(0b10101).parity());
+}
+
+#[test]
+fn hamming_distance() {
+    let a = Bitmap128::from(0b11110000);
+    let b = Bitmap128::from(0b11001100);
+
+    assert_eq!(a.hamming_distance(&a), 0);
+    assert_eq!(a.hamming_distance(&b), 4);
+    assert_eq!(b.hamming_distance(&a), 4);
+    assert_eq!(
+        Bitmap128::default().hamming_distance(&Bitmap128::new(true)),
+        Bitmap128::MAP_LENGTH
+    );
+}
+
+#[test]
+fn set_bits_iterates_ascending() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let bitmap = Bitmap128::from(0b1010010) | Bitmap128::from_set(last).unwrap();
+
+    assert_eq!(bitmap.set_bits().collect::<Vec<_>>(), vec![1, 4, 6, last]);
+    assert_eq!(bitmap.set_bits().len(), 4);
+    assert_eq!(Bitmap128::default().set_bits().next(), None);
+    assert_eq!(
+        Bitmap128::new(true).set_bits().collect::<Vec<_>>(),
+        (0..Bitmap128::MAP_LENGTH).collect::<Vec<_>>()
+    );
+
+    let mut iter = bitmap.set_bits();
+    iter.next();
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+fn clear_bits_iterates_ascending() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let bitmap = !(Bitmap128::from(0b1010010) | Bitmap128::from_set(last).unwrap());
+
+    assert_eq!(bitmap.clear_bits().collect::<Vec<_>>(), vec![1, 4, 6, last]);
+    assert_eq!(bitmap.clear_bits().len(), bitmap.count_zeros() as usize);
+    assert_eq!(Bitmap128::new(true).clear_bits().next(), None);
+    assert_eq!(
+        Bitmap128::default().clear_bits().collect::<Vec<_>>(),
+        (0..Bitmap128::MAP_LENGTH).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn bits_visits_every_position() {
+    let last = Bitmap128::MAP_LENGTH - 1;
+    let bitmap = Bitmap128::from(0b110) | Bitmap128::from_set(last).unwrap();
+    let bits: Vec<bool> = bitmap.bits().collect();
+
+    assert_eq!(bits.len(), Bitmap128::MAP_LENGTH);
+    for (i, bit) in bits.into_iter().enumerate() {
+        assert_eq!(bitmap.get(i), Ok(bit));
+    }
+
+    let mut iter = bitmap.bits();
+    assert_eq!(iter.len(), Bitmap128::MAP_LENGTH);
+    iter.next();
+    assert_eq!(iter.len(), last);
+}
+
+#[test]
+fn into_iterator_yields_bits() {
+    let bitmap = Bitmap128::from(0b1101);
+
+    let mut count = 0;
+    for (i, bit) in bitmap.into_iter().enumerate() {
+        assert_eq!(bitmap.get(i), Ok(bit));
+        count += 1;
+    }
+    assert_eq!(count, Bitmap128::MAP_LENGTH);
+
+    let mut set = 0;
+    for bit in &bitmap {
+        if bit {
+            set += 1;
+        }
+    }
+    assert_eq!(set, 3);
+}
+
+#[test]
+fn from_iterator_of_bools() {
+    let bitmap = Bitmap128::from(0b1011) | Bitmap128::from_set(Bitmap128::MAP_LENGTH - 1).unwrap();
+
+    assert_eq!(bitmap.bits().collect::<Bitmap128>(), bitmap);
+    assert_eq!(
+        *vec![false, true, true].into_iter().collect::<Bitmap128>(),
+        0b110
+    );
+    assert_eq!(
+        std::iter::empty().collect::<Bitmap128>(),
+        Bitmap128::default()
+    );
+    assert_eq!(
+        (0..Bitmap128::MAP_LENGTH + 10)
+            .map(|_| true)
+            .collect::<Bitmap128>(),
+        Bitmap128::new(true)
+    );
+}
+
+#[test]
+fn from_indices_ignores_out_of_bounds() {
+    let length = Bitmap128::MAP_LENGTH;
+    let bitmap = Bitmap128::from_indices(vec![6, 1, length, 1, length + 5]);
+
+    assert_eq!(*bitmap, 0b1000010);
+    assert_eq!(Bitmap128::from_indices(bitmap.set_bits()), bitmap);
+    assert_eq!(Bitmap128::from_indices(0..length), Bitmap128::new(true));
+}
+
+#[test]
+fn from_indices_checked() {
+    let length = Bitmap128::MAP_LENGTH;
+
+    assert_eq!(
+        *Bitmap128::from_indices_checked(vec![6, 1, 1]).unwrap(),
+        0b1000010
+    );
+    assert_eq!(
+        Bitmap128::from_indices_checked(vec![length - 1]),
+        Ok(Bitmap128::from_set(length - 1).unwrap())
+    );
+    assert!(Bitmap128::from_indices_checked(vec![1, length]).is_err());
+}
+
+#[test]
+fn wrapping_arithmetic() {
+    let max = Bitmap128::new(true);
+
+    assert_eq!(*Bitmap128::from(5).wrapping_add(3), 8);
+    assert_eq!(*max.wrapping_add(1), 0);
+    assert_eq!(*Bitmap128::from(5).wrapping_sub(3), 2);
+    assert_eq!(Bitmap128::default().wrapping_sub(1), max);
+    assert_eq!(*Bitmap128::from(5).wrapping_mul(3), 15);
+    assert_eq!(max.wrapping_mul(2), max - 1);
+}
+
+#[test]
+fn checked_arithmetic() {
+    let max = Bitmap128::new(true);
+
+    assert_eq!(Bitmap128::from(5).checked_add(3), Some(Bitmap128::from(8)));
+    assert_eq!(max.checked_add(1), None);
+    assert_eq!(
+        Bitmap128::from(5).checked_sub(5),
+        Some(Bitmap128::default())
+    );
+    assert_eq!(Bitmap128::default().checked_sub(1), None);
+    assert_eq!(Bitmap128::from(5).checked_mul(3), Some(Bitmap128::from(15)));
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(Bitmap128::from(15).checked_div(4), Some(Bitmap128::from(3)));
+    assert_eq!(Bitmap128::from(15).checked_div(0), None);
+}
+
+#[test]
+fn saturating_arithmetic() {
+    let max = Bitmap128::new(true);
+
+    assert_eq!(*Bitmap128::from(5).saturating_add(3), 8);
+    assert_eq!(max.saturating_add(1), max);
+    assert_eq!(*Bitmap128::from(5).saturating_sub(3), 2);
+    assert_eq!(*Bitmap128::from(5).saturating_sub(6), 0);
+    assert_eq!(
+        *Bitmap128::from(5).saturating_add_bitmap(Bitmap128::from(3)),
+        8
+    );
+    assert_eq!((max - 1).saturating_add_bitmap(Bitmap128::from(2)), max);
+    assert_eq!(
+        *Bitmap128::from(5).saturating_sub_bitmap(Bitmap128::from(3)),
+        2
+    );
+    assert_eq!(*Bitmap128::default().saturating_sub_bitmap(max), 0);
+}
+
+#[test]
+fn overflowing_arithmetic() {
+    let max = Bitmap128::new(true);
+
+    assert_eq!(
+        Bitmap128::from(5).overflowing_add(3),
+        (Bitmap128::from(8), false)
+    );
+    assert_eq!(max.overflowing_add(2), (Bitmap128::from(1), true));
+    assert_eq!(
+        Bitmap128::from(5).overflowing_sub(3),
+        (Bitmap128::from(2), false)
+    );
+    assert_eq!(Bitmap128::from(1).overflowing_sub(2), (max, true));
+    assert_eq!(
+        Bitmap128::from(5).overflowing_mul(3),
+        (Bitmap128::from(15), false)
+    );
+    assert_eq!(max.overflowing_mul(2), (max - 1, true));
+}
+
+#[test]
+fn be_bytes() {
+    let bitmap = Bitmap128::from(0b1011) | (Bitmap128::from(1) << (Bitmap128::MAP_LENGTH - 1));
+    let bytes = bitmap.to_be_bytes();
+
+    assert_eq!(bytes, (*bitmap).to_be_bytes());
+    assert_eq!(bytes[0] & 0x80, 0x80);
+    assert_eq!(bytes[bytes.len() - 1] & 0xF, 0b1011);
+    assert_eq!(Bitmap128::from_be_bytes(bytes), bitmap);
+}
+
+#[test]
+fn le_bytes() {
+    let bitmap = Bitmap128::from(0b1011) | (Bitmap128::from(1) << (Bitmap128::MAP_LENGTH - 1));
+    let bytes = bitmap.to_le_bytes();
+
+    assert_eq!(bytes, (*bitmap).to_le_bytes());
+    assert_eq!(bytes[0] & 0xF, 0b1011);
+    assert_eq!(bytes[bytes.len() - 1] & 0x80, 0x80);
+    assert_eq!(Bitmap128::from_le_bytes(bytes), bitmap);
+}
+
+#[test]
+fn byte_array_conversions() {
+    let bitmap = Bitmap128::from(0b1011) | (Bitmap128::from(1) << (Bitmap128::MAP_LENGTH - 1));
+    let bytes: [u8; std::mem::size_of::<u128>()] = bitmap.into();
+
+    assert_eq!(bytes, bitmap.to_le_bytes());
+    assert_eq!(Bitmap128::from(bytes), bitmap);
+}
+
+#[test]
+fn from_str_parses_binary() {
+    assert_eq!(
+        "10110011".parse::<Bitmap128>(),
+        Ok(Bitmap128::from(0b10110011))
+    );
+    assert_eq!("0".parse::<Bitmap128>(), Ok(Bitmap128::default()));
+
+    let full = "1".repeat(Bitmap128::MAP_LENGTH);
+    assert_eq!(full.parse::<Bitmap128>(), Ok(Bitmap128::new(true)));
+
+    let msb = String::from("1") + &"0".repeat(Bitmap128::MAP_LENGTH - 1);
+    assert_eq!(
+        msb.parse::<Bitmap128>(),
+        Ok(Bitmap128::from(1) << (Bitmap128::MAP_LENGTH - 1))
+    );
+}
+
+#[test]
+fn from_str_errors() {
+    assert_eq!("".parse::<Bitmap128>(), Err(ParseBitmapError::Empty));
+    assert_eq!(
+        "10x1".parse::<Bitmap128>(),
+        Err(ParseBitmapError::InvalidDigit {
+            character: 'x',
+            index: 2
+        })
+    );
+
+    let too_long = "0".repeat(Bitmap128::MAP_LENGTH + 1);
+    assert_eq!(
+        too_long.parse::<Bitmap128>(),
+        Err(ParseBitmapError::TooLong {
+            length: Bitmap128::MAP_LENGTH + 1,
+            capacity: Bitmap128::MAP_LENGTH
+        })
+    );
+}
+
+#[test]
+fn from_hex_str_parses() {
+    assert_eq!(Bitmap128::from_hex_str("0xA5"), Ok(Bitmap128::from(0xA5)));
+    assert_eq!(Bitmap128::from_hex_str("0XaB"), Ok(Bitmap128::from(0xAB)));
+    assert_eq!(Bitmap128::from_hex_str("7"), Ok(Bitmap128::from(7)));
+
+    let full = "F".repeat(Bitmap128::MAP_LENGTH / 4);
+    assert_eq!(Bitmap128::from_hex_str(&full), Ok(Bitmap128::new(true)));
+}
+
+#[test]
+fn from_hex_str_errors() {
+    assert_eq!(Bitmap128::from_hex_str("0x"), Err(ParseBitmapError::Empty));
+    assert_eq!(
+        Bitmap128::from_hex_str("0x1g"),
+        Err(ParseBitmapError::InvalidDigit {
+            character: 'g',
+            index: 3
+        })
+    );
+
+    let too_long = "0".repeat(Bitmap128::MAP_LENGTH / 4 + 1);
+    assert_eq!(
+        Bitmap128::from_hex_str(&too_long),
+        Err(ParseBitmapError::TooLong {
+            length: Bitmap128::MAP_LENGTH / 4 + 1,
+            capacity: Bitmap128::MAP_LENGTH / 4
+        })
+    );
+}
+
+#[test]
+fn display_pads_to_full_width() {
+    let expected = "0".repeat(Bitmap128::MAP_LENGTH - 4) + "1001";
+    assert_eq!(Bitmap128::from(9).to_string(), expected);
+    assert_eq!(
+        Bitmap128::default().to_string(),
+        "0".repeat(Bitmap128::MAP_LENGTH)
+    );
+    assert_eq!(
+        Bitmap128::new(true).to_string(),
+        "1".repeat(Bitmap128::MAP_LENGTH)
+    );
+}
+
+#[test]
+fn display_round_trips_through_from_str() {
+    let bitmap = Bitmap128::from(0b10110011);
+    assert_eq!(bitmap.to_string().parse::<Bitmap128>(), Ok(bitmap));
+}
+
+#[test]
+fn binary_formatting() {
+    let bitmap = Bitmap128::from(0b1011);
+
+    assert_eq!(format!("{:b}", bitmap), "1011");
+    assert_eq!(format!("{:#b}", bitmap), "0b1011");
+    assert_eq!(format!("{:08b}", bitmap), "00001011");
+    assert_eq!(
+        format!("{:0w$b}", bitmap, w = Bitmap128::MAP_LENGTH),
+        bitmap.to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn to_binary_string_grouped() {
+    let bitmap = Bitmap128::from(0b10110011);
+    let grouped = bitmap.to_binary_string_grouped(4);
+
+    assert!(grouped.ends_with("1011_0011"));
+    assert_eq!(grouped.replace('_', ""), bitmap.to_string());
+    assert_eq!(grouped.matches('_').count(), Bitmap128::MAP_LENGTH / 4 - 1);
+
+    assert_eq!(bitmap.to_binary_string_grouped(0), bitmap.to_string());
+    assert_eq!(
+        bitmap.to_binary_string_grouped(Bitmap128::MAP_LENGTH),
+        bitmap.to_string()
+    );
+
+    let grouped = Bitmap128::new(true).to_binary_string_grouped(3);
+    assert!(grouped.starts_with("111_"));
+}
+
+#[test]
+fn errors_are_typed() {
+    let mut bitmap = Bitmap128::default();
+    let out_of_bounds = BitmapError::IndexOutOfBounds {
+        index: Bitmap128::MAP_LENGTH,
+        capacity: Bitmap128::MAP_LENGTH,
+    };
+
+    assert_eq!(bitmap.get(Bitmap128::MAP_LENGTH), Err(out_of_bounds));
+    assert_eq!(bitmap.set(Bitmap128::MAP_LENGTH, true), Err(out_of_bounds));
+    assert_eq!(bitmap.toggle(Bitmap128::MAP_LENGTH), Err(out_of_bounds));
+    assert_eq!(
+        bitmap.swap_bits(0, Bitmap128::MAP_LENGTH),
+        Err(out_of_bounds)
+    );
+    assert_eq!(
+        Bitmap128::from_set(Bitmap128::MAP_LENGTH),
+        Err(out_of_bounds)
+    );
+    assert_eq!(
+        Bitmap128::from_sorted_indices(&[3, 1, 5]),
+        Err(BitmapError::UnsortedIndices {
+            index: 1,
+            previous: 3
+        })
+    );
+    assert_eq!(
+        out_of_bounds.to_string(),
+        format!(
+            "Tried to access bit that's out of range of the bitmap (range: {}, index: {})",
+            Bitmap128::MAP_LENGTH,
+            Bitmap128::MAP_LENGTH
+        )
+    );
+}
+
+fn fill_generically<T: BitmapOps>(bitmap: &mut T) {
+    for i in 0..T::capacity() {
+        bitmap.set(i, true).unwrap();
+    }
+}
+
+#[test]
+fn bitmap_ops_trait() {
+    let mut bitmap = Bitmap128::default();
+    assert!(BitmapOps::is_empty(&bitmap));
+    assert_eq!(<Bitmap128 as BitmapOps>::capacity(), Bitmap128::MAP_LENGTH);
+
+    fill_generically(&mut bitmap);
+    assert!(BitmapOps::is_full(&bitmap));
+    assert_eq!(BitmapOps::count_ones(&bitmap), Bitmap128::MAP_LENGTH);
+    assert_eq!(BitmapOps::get(&bitmap, Bitmap128::MAP_LENGTH - 1), Ok(true));
+    assert!(BitmapOps::set(&mut bitmap, Bitmap128::MAP_LENGTH, true).is_err());
+}
+
+#[test]
+fn unchecked_access() {
+    let mut bitmap = Bitmap128::default();
+    let last = Bitmap128::MAP_LENGTH - 1;
+
+    unsafe {
+        bitmap.set_unchecked(0, true);
+        bitmap.set_unchecked(last, true);
+        assert!(bitmap.get_unchecked(0));
+        assert!(bitmap.get_unchecked(last));
+        assert!(!bitmap.get_unchecked(1));
+
+        bitmap.set_unchecked(0, false);
+        assert!(!bitmap.get_unchecked(0));
+    }
+
+    assert_eq!(bitmap, Bitmap128::from_set(last).unwrap());
+}
+
+#[test]
+fn get_range() {
+    let bitmap = Bitmap128::from(0b1011_0110);
+    let last = Bitmap128::MAP_LENGTH;
+
+    assert_eq!(bitmap.get_range(0, 8), Ok(0b1011_0110));
+    assert_eq!(bitmap.get_range(2, 5), Ok(0b101));
+    assert_eq!(bitmap.get_range(3, 3), Ok(0));
+    assert_eq!(bitmap.get_range(last, last), Ok(0));
+    assert_eq!(
+        Bitmap128::new(true).get_range(last - last.min(64), last),
+        Ok(u64::MAX >> (64 - last.min(64)))
+    );
+
+    assert_eq!(
+        bitmap.get_range(5, 2),
+        Err(BitmapError::RangeOutOfBounds {
+            begin: 5,
+            end: 2,
+            capacity: last
+        })
+    );
+    assert!(bitmap.get_range(0, last + 1).is_err());
+}
+
+#[test]
+fn set_bits_from_value() {
+    let mut bitmap = Bitmap128::new(true);
+    let last = Bitmap128::MAP_LENGTH;
+
+    bitmap.set_bits_from_value(2, 6, 0b1111_0101).unwrap();
+    assert_eq!(bitmap.get_range(0, 8), Ok(0b1101_0111));
+    assert_eq!(bitmap.count_ones() as usize, last - 2);
+
+    bitmap.set_bits_from_value(last, last, u64::MAX).unwrap();
+    bitmap.set_bits_from_value(0, last.min(64), 0).unwrap();
+    assert_eq!(bitmap.get_range(0, last.min(64)), Ok(0));
+
+    assert!(bitmap.set_bits_from_value(4, 3, 0).is_err());
+    assert!(bitmap.set_bits_from_value(0, last + 1, 0).is_err());
+}
+
+#[test]
+fn index_gets_bits() {
+    let bitmap = Bitmap128::from(0b1010) | Bitmap128::from_set(Bitmap128::MAP_LENGTH - 1).unwrap();
+
+    assert!(!bitmap[0]);
+    assert!(bitmap[1]);
+    assert!(bitmap[3]);
+    assert!(bitmap[Bitmap128::MAP_LENGTH - 1]);
+}
+
+#[test]
+#[should_panic(expected = "bitmap index out of bounds")]
+fn index_out_of_bounds_panics() {
+    let _ = Bitmap128::default()[Bitmap128::MAP_LENGTH];
+}
+
+#[test]
+fn subset_and_superset() {
+    let small = Bitmap128::from(0b0101);
+    let large = Bitmap128::from(0b1101);
+    let other = Bitmap128::from(0b0010);
+
+    assert!(small.is_subset(&large));
+    assert!(small.is_subset(&small));
+    assert!(!large.is_subset(&small));
+    assert!(!other.is_subset(&large));
+    assert!(Bitmap128::default().is_subset(&other));
+
+    assert!(large.is_superset(&small));
+    assert!(!small.is_superset(&large));
+    assert!(Bitmap128::new(true).is_superset(&large));
+}
+
+#[test]
+fn is_disjoint() {
+    let a = Bitmap128::from(0b0011);
+
+    assert!(a.is_disjoint(&Bitmap128::from(0b1100)));
+    assert!(!a.is_disjoint(&Bitmap128::from(0b0110)));
+    assert!(!a.is_disjoint(&a));
+    assert!(a.is_disjoint(&Bitmap128::default()));
+    assert!(Bitmap128::default().is_disjoint(&Bitmap128::default()));
+    assert!(a.is_disjoint(&!a));
+}
+
+#[test]
+fn difference() {
+    let a = Bitmap128::from(0b1101);
+    let b = Bitmap128::from(0b0110);
+
+    assert_eq!(a.difference(&b), Bitmap128::from(0b1001));
+    assert_eq!(b.difference(&a), Bitmap128::from(0b0010));
+    assert_eq!(a.difference(&a), Bitmap128::default());
+    assert_eq!(a.difference(&Bitmap128::default()), a);
+    assert_eq!(Bitmap128::new(true).difference(&a), !a);
+}
+
+#[test]
+fn next_permutation_enumerates_same_popcount() {
+    let mut bitmap = Bitmap128::from(0b11);
+    let mut count = 1;
+    while let Some(next) = bitmap.next_permutation() {
+        assert!(next > bitmap);
+        assert_eq!(next.count_ones(), 2);
+        bitmap = next;
+        count += 1;
+    }
+
+    // Every way of choosing 2 bits, ending with both at the high end
+    assert_eq!(
+        count,
+        Bitmap128::MAP_LENGTH * (Bitmap128::MAP_LENGTH - 1) / 2
+    );
+    assert_eq!(bitmap, Bitmap128::from(0b11) << (Bitmap128::MAP_LENGTH - 2));
+    assert_eq!(Bitmap128::default().next_permutation(), None);
+}
+
+#[test]
+fn prev_permutation_inverts_next_permutation() {
+    let mut bitmap = Bitmap128::from(0b111) << (Bitmap128::MAP_LENGTH - 3);
+    let mut count = 1;
+    while let Some(previous) = bitmap.prev_permutation() {
+        assert!(previous < bitmap);
+        assert_eq!(previous.next_permutation(), Some(bitmap));
+        bitmap = previous;
+        count += 1;
+    }
+
+    let n = Bitmap128::MAP_LENGTH;
+    assert_eq!(count, n * (n - 1) * (n - 2) / 6);
+    assert_eq!(bitmap, Bitmap128::from(0b111));
+    assert_eq!(Bitmap128::default().prev_permutation(), None);
+    assert_eq!(Bitmap128::new(true).prev_permutation(), None);
+}
@@ -0,0 +1,130 @@
+use fixed_bitmaps::{Bitmap128, Bitmap16, Bitmap32, Bitmap64, Bitmap8};
+use std::convert::TryFrom;
+
+#[test]
+fn widening_equality() {
+    assert!(Bitmap32::from(42) == Bitmap64::from(42));
+    assert!(Bitmap64::from(42) == Bitmap32::from(42));
+    assert!(Bitmap8::from(u8::MAX) == Bitmap16::from(0xFF));
+    assert!(Bitmap16::from(0xFF) == Bitmap8::from(u8::MAX));
+    assert!(Bitmap8::from(7) == Bitmap128::from(7));
+    assert!(Bitmap64::from(u64::MAX) == Bitmap128::from(u64::MAX as u128));
+}
+
+#[test]
+fn widening_inequality() {
+    assert!(Bitmap32::from(42) != Bitmap64::from(43));
+    assert!(Bitmap8::from(u8::MAX) != Bitmap16::from(u16::MAX));
+    assert!(Bitmap16::from(u16::MAX) != Bitmap8::from(u8::MAX));
+    assert!(Bitmap32::from(1) != Bitmap128::from(1 << 32 | 1));
+}
+
+#[test]
+fn narrowing_try_from() {
+    assert_eq!(
+        Bitmap32::try_from(Bitmap64::from(42)),
+        Ok(Bitmap32::from(42))
+    );
+    assert_eq!(
+        Bitmap32::try_from(Bitmap64::from(u32::MAX as u64)),
+        Ok(Bitmap32::from(u32::MAX))
+    );
+    assert_eq!(
+        Bitmap8::try_from(Bitmap128::from(0xFF)),
+        Ok(Bitmap8::from(0xFF))
+    );
+    assert!(Bitmap32::try_from(Bitmap64::from(1 << 32)).is_err());
+    assert!(Bitmap8::try_from(Bitmap16::from(0x100)).is_err());
+    assert!(Bitmap64::try_from(Bitmap128::new(true)).is_err());
+}
+
+#[test]
+fn narrowing_try_from_error() {
+    let error = Bitmap16::try_from(Bitmap32::from(u32::MAX)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Tried to convert a 32 bit bitmap into a 16 bit bitmap, but it has bits set beyond index 15"
+    );
+}
+
+#[test]
+fn widening_from() {
+    assert_eq!(Bitmap16::from(Bitmap8::from(u8::MAX)), Bitmap16::from(0xFF));
+    assert_eq!(Bitmap64::from(Bitmap32::from(42)), Bitmap64::from(42));
+    assert_eq!(
+        Bitmap128::from(Bitmap64::from(u64::MAX)),
+        Bitmap128::from(u64::MAX as u128)
+    );
+
+    let widened: Bitmap64 = Bitmap8::from(0b1010).into();
+    assert_eq!(widened, Bitmap64::from(0b1010));
+}
+
+#[test]
+fn widen_then_narrow() {
+    let original = Bitmap16::from(0xBEEF);
+    let widened: Bitmap128 = original.into();
+    assert_eq!(Bitmap16::try_from(widened), Ok(original));
+}
+
+#[test]
+fn split_into_halves() {
+    assert_eq!(
+        Bitmap16::from(0xABCD).split(),
+        (Bitmap8::from(0xCD), Bitmap8::from(0xAB))
+    );
+    assert_eq!(
+        Bitmap32::from(0x1234_5678).split(),
+        (Bitmap16::from(0x5678), Bitmap16::from(0x1234))
+    );
+    assert_eq!(
+        Bitmap64::from(u64::MAX << 32).split(),
+        (Bitmap32::from(0), Bitmap32::from(u32::MAX))
+    );
+    assert_eq!(
+        Bitmap128::from(1).split(),
+        (Bitmap64::from(1), Bitmap64::from(0))
+    );
+}
+
+#[test]
+fn concat_halves() {
+    assert_eq!(
+        Bitmap16::concat(Bitmap8::from(0xCD), Bitmap8::from(0xAB)),
+        Bitmap16::from(0xABCD)
+    );
+    assert_eq!(
+        Bitmap64::concat(Bitmap32::from(0), Bitmap32::from(u32::MAX)),
+        Bitmap64::from(u64::MAX << 32)
+    );
+
+    let bitmap = Bitmap128::from(0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    let (low, high) = bitmap.split();
+    assert_eq!(Bitmap128::concat(low, high), bitmap);
+
+    let bitmap = Bitmap32::from(0xDEAD_BEEF);
+    let (low, high) = bitmap.split();
+    assert_eq!(Bitmap32::concat(low, high), bitmap);
+}
+
+#[test]
+fn interleave_and_deinterleave() {
+    let low = Bitmap32::from(0b1011);
+    let high = Bitmap32::from(0b0110);
+    let interleaved = Bitmap64::interleave(low, high);
+    assert_eq!(interleaved, Bitmap64::from(0b0110_1101));
+    assert_eq!(interleaved.deinterleave(), (low, high));
+
+    assert_eq!(
+        Bitmap64::interleave(Bitmap32::from(u32::MAX), Bitmap32::from(0)),
+        Bitmap64::from_alternating(true)
+    );
+    assert_eq!(
+        Bitmap64::from(u64::MAX).deinterleave(),
+        (Bitmap32::from(u32::MAX), Bitmap32::from(u32::MAX))
+    );
+
+    let bitmap = Bitmap16::from(0xBEEF);
+    let (even, odd) = bitmap.deinterleave();
+    assert_eq!(Bitmap16::interleave(even, odd), bitmap);
+}
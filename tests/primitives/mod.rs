@@ -4,3 +4,5 @@ mod bitmap32;
 mod bitmap64;
 mod bitmap8;
 mod bitmap_arch;
+mod conversions;
+mod nibbles;
@@ -0,0 +1,29 @@
+use fixed_bitmaps::Bitmap8;
+
+#[test]
+fn to_nibbles_splits_byte() {
+    assert_eq!(Bitmap8::from(0xAB).to_nibbles(), (0xA, 0xB));
+    assert_eq!(Bitmap8::from(0x0F).to_nibbles(), (0x0, 0xF));
+    assert_eq!(Bitmap8::from(0xF0).to_nibbles(), (0xF, 0x0));
+    assert_eq!(Bitmap8::default().to_nibbles(), (0, 0));
+}
+
+#[test]
+fn from_nibbles_round_trips() {
+    for value in 0..=u8::MAX {
+        let (high, low) = Bitmap8::from(value).to_nibbles();
+        assert_eq!(*Bitmap8::from_nibbles(high, low), value);
+    }
+}
+
+#[test]
+#[should_panic]
+fn from_nibbles_high_too_large() {
+    Bitmap8::from_nibbles(0x10, 0);
+}
+
+#[test]
+#[should_panic]
+fn from_nibbles_low_too_large() {
+    Bitmap8::from_nibbles(0, 0x10);
+}